@@ -0,0 +1,410 @@
+use crate::{info, success};
+use clap::{
+	builder::{PossibleValue, PossibleValuesParser},
+	ArgAction, Args, CommandFactory, Parser, Subcommand, ValueEnum, ValueHint,
+};
+use clap_complete::{generate, Shell};
+use clap_mangen::Man;
+use json::{object, JsonValue};
+use licer::{all_licenses, LicerError};
+use std::{
+	fs::File,
+	io::{stdout, Write},
+	path::{Path, PathBuf},
+};
+
+#[derive(Parser)]
+#[command(
+	name = "licer",
+	version,
+	about = "A simple CLI for quickly generating repository licenses",
+	disable_version_flag = true,
+	after_help = "Running licer with a license or flags but no command runs the new command, e.g. licer mit -n Zahtec\nRunning licer without anything uses the default license of the config files, or starts the wizard in a terminal\n\nExit codes: 0 success, 1 aborted, 2 usage, 3 unknown license, 4 missing info, 5 IO, 6 parse"
+)]
+pub struct Cli {
+	#[arg(short = 'v', long, action = ArgAction::Version, help = "Print version")]
+	pub version: Option<bool>,
+	#[arg(
+		long,
+		global = true,
+		value_enum,
+		default_value_t,
+		help = "Output format"
+	)]
+	pub format: Format,
+	#[arg(
+		short,
+		long,
+		global = true,
+		conflicts_with = "verbose",
+		help = "Print only errors, warnings and the requested output"
+	)]
+	pub quiet: bool,
+	#[arg(long, global = true, help = "Print where each license field came from")]
+	pub verbose: bool,
+	#[arg(
+		long,
+		global = true,
+		value_enum,
+		default_value_t,
+		help = "When to color the output"
+	)]
+	pub color: ColorMode,
+	#[command(subcommand)]
+	pub command: Option<Command>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+	#[default]
+	Text,
+	Json,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ColorMode {
+	#[default]
+	Auto,
+	Always,
+	Never,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+	#[command(about = "Write a license to the project")]
+	New(NewArgs),
+	#[command(about = "Choose a license with an interactive wizard and write it")]
+	Init(WriteArgs),
+	#[command(about = "Check that the license file matches the license of the manifest")]
+	Check {
+		#[arg(
+			short,
+			long,
+			help = "License file to check [default: LICENSE, COPYING, ...]"
+		)]
+		file: Option<PathBuf>,
+	},
+	#[command(about = "Detect the license of a license file")]
+	Detect {
+		#[arg(value_hint = ValueHint::FilePath, help = "License file to detect [default: LICENSE, COPYING, ...]")]
+		file: Option<PathBuf>,
+	},
+	#[command(about = "List every available license")]
+	List(ListArgs),
+	#[command(about = "Search the licenses by name, SPDX id and text")]
+	Search {
+		#[arg(help = "Search term")]
+		term: String,
+	},
+	#[command(about = "Show the permissions, conditions and limitations of a license")]
+	Info {
+		#[arg(help = "License id")]
+		license: String,
+	},
+	#[command(about = "Compare licenses side by side")]
+	Compare {
+		#[arg(required = true, num_args = 2.., help = "License ids")]
+		licenses: Vec<String>,
+	},
+	#[command(about = "Print or insert an SPDX license header for source files")]
+	Header(HeaderArgs),
+	#[command(about = "Print the completion script of a shell")]
+	Completions {
+		#[arg(help = "Shell to complete subcommands, flags and licenses in")]
+		shell: Shell,
+	},
+	#[command(
+		about = "Print the man page, or write the man pages of every command to a directory"
+	)]
+	Manpage {
+		#[arg(short, long, value_hint = ValueHint::DirPath, help = "Directory to write licer.1 and licer-<command>.1 to")]
+		directory: Option<PathBuf>,
+	},
+}
+
+#[derive(Args, Default)]
+pub struct InfoArgs {
+	#[arg(
+		short,
+		long = "name",
+		value_name = "NAME",
+		help = "Author name (repeated)"
+	)]
+	pub names: Vec<String>,
+	#[arg(
+		short,
+		long = "email",
+		value_name = "EMAIL",
+		help = "Author email (repeated), associated with the names in order"
+	)]
+	pub emails: Vec<String>,
+	#[arg(
+		long = "holder",
+		value_name = "HOLDER",
+		help = "Copyright holder instead of the authors (repeated), may start with its own years"
+	)]
+	pub holders: Vec<String>,
+	#[arg(
+		long,
+		value_name = "list | lines | et-al[=N]",
+		help = "Join holders in a list, put each on its own line, or shorten them with et al."
+	)]
+	pub holder_format: Option<String>,
+	#[arg(short, long, help = "Project name")]
+	pub project: Option<String>,
+	#[arg(short, long, help = "Project url")]
+	pub url: Option<String>,
+	#[arg(short, long, help = "Copyright year")]
+	pub year: Option<String>,
+}
+
+#[derive(Args, Default)]
+pub struct WriteArgs {
+	#[command(flatten)]
+	pub info: InfoArgs,
+	#[arg(
+		short,
+		long,
+		value_name = "LANG",
+		help = "Language code of an official translation"
+	)]
+	pub lang: Option<String>,
+	#[arg(short, long, value_hint = ValueHint::DirPath, help = "Directory to write the license to")]
+	pub directory: Option<String>,
+	#[arg(
+		short,
+		long,
+		value_name = "FILE NAME",
+		help = "File name of the license [default: LICENSE]"
+	)]
+	pub file: Option<String>,
+	#[arg(long, help = "Save a copy of an existing license first")]
+	pub backup: bool,
+	#[arg(
+		long,
+		help = "Print the license and a diff of every manifest change without writing"
+	)]
+	pub dry_run: bool,
+	#[arg(long, help = "Overwrite an existing license without confirming")]
+	pub force: bool,
+	#[arg(
+		long,
+		help = "Follow the GNU conventions of writing COPYING and printing the notice"
+	)]
+	pub gnu: bool,
+	#[arg(
+		short,
+		long,
+		help = "Write the license to every package of the workspace as well"
+	)]
+	pub recursive: bool,
+	#[arg(long, help = "Print only the license")]
+	pub stdout: bool,
+}
+
+#[derive(Args, Default)]
+pub struct NewArgs {
+	#[arg(help = "License id [default: the license of the config files or manifest]")]
+	pub license: Option<String>,
+	#[arg(
+		short,
+		long,
+		value_name = "PATH",
+		help = "Custom license template to use"
+	)]
+	pub template: Option<String>,
+	#[command(flatten)]
+	pub write: WriteArgs,
+}
+
+impl NewArgs {
+	pub fn init(write: WriteArgs) -> NewArgs {
+		NewArgs {
+			write,
+			..NewArgs::default()
+		}
+	}
+}
+
+#[derive(Args)]
+pub struct ListArgs {
+	#[arg(
+		long,
+		conflicts_with = "copyleft",
+		help = "Only permissive and public domain licenses"
+	)]
+	pub permissive: bool,
+	#[arg(long, help = "Only copyleft and weak copyleft licenses")]
+	pub copyleft: bool,
+	#[arg(long, help = "Only licenses approved by the OSI")]
+	pub osi: bool,
+	#[arg(long, help = "Only licenses the FSF considers free")]
+	pub fsf_libre: bool,
+	#[arg(
+		long,
+		value_name = "year | name | email | project | url",
+		help = "Only licenses requiring the info (repeated)"
+	)]
+	pub requires: Vec<String>,
+}
+
+#[derive(Args, Default)]
+pub struct HeaderArgs {
+	#[arg(
+		short,
+		long,
+		help = "License id or SPDX expression [default: the license of the config files or manifest]"
+	)]
+	pub license: Option<String>,
+	#[command(flatten)]
+	pub info: InfoArgs,
+	#[arg(
+		short,
+		long,
+		value_name = "STYLE",
+		help = "Comment style: slash, hash, block, dash or html [default: by file extension, else slash]"
+	)]
+	pub style: Option<String>,
+	#[arg(value_hint = ValueHint::FilePath, help = "Files to insert the header into instead of printing it")]
+	pub files: Vec<PathBuf>,
+}
+
+pub fn command() -> clap::Command {
+	Cli::command().mut_subcommand("new", |new| {
+		new.after_help(format!(
+			"Any other license of the SPDX License List can be written by its identifier, e.g. licer new BSD-1-Clause\n\n{}",
+			info::table(&all_licenses())
+		))
+	})
+}
+
+pub fn completions(shell: Shell) {
+	let mut licenses = all_licenses()
+		.into_iter()
+		.flat_map(|license| [(license.id, license.name), (license.spdx, license.name)])
+		.collect::<Vec<(&str, &str)>>();
+
+	licenses.sort_unstable();
+	licenses.dedup_by_key(|(id, _)| *id);
+
+	let parser = PossibleValuesParser::new(
+		licenses
+			.iter()
+			.map(|(id, name)| PossibleValue::new(*id).help(*name)),
+	);
+
+	let mut command = [
+		("new", "license"),
+		("info", "license"),
+		("compare", "licenses"),
+		("header", "license"),
+	]
+	.into_iter()
+	.fold(command(), |command, (name, arg)| {
+		command.mut_subcommand(name, |subcommand| {
+			subcommand.mut_arg(arg, |arg| arg.value_parser(parser.clone()))
+		})
+	});
+
+	let mut script = Vec::new();
+
+	generate(shell, &mut command, "licer", &mut script);
+
+	let mut script = String::from_utf8_lossy(&script).into_owned();
+
+	let (block, candidate) = match shell {
+		Shell::PowerShell => (
+			"        'licer;{}' {\n",
+			"            [CompletionResult]::new('{id}', '{id}', [CompletionResultType]::ParameterValue, '{name}')\n",
+		),
+		Shell::Elvish => (
+			"        &'licer;{}'= {\n",
+			"            cand '{id}' '{name}'\n",
+		),
+		_ => ("", ""),
+	};
+
+	for name in ["new", "info", "compare", "header"]
+		.iter()
+		.filter(|_| !block.is_empty())
+	{
+		let block = block.replace("{}", name);
+
+		assert!(
+			script.contains(&block),
+			"The completion script has no block for '{}'",
+			name
+		);
+		let candidates = licenses
+			.iter()
+			.map(|(id, name)| {
+				candidate
+					.replace("{id}", id)
+					.replace("{name}", &name.replace('\'', "''"))
+			})
+			.collect::<String>();
+
+		script = script.replacen(&block, &format!("{}{}", block, candidates), 1);
+	}
+
+	out!("{}", script);
+}
+
+fn render_manpage(command: clap::Command, out: &mut dyn Write) -> std::io::Result<()> {
+	let licenses = matches!(
+		command.get_display_name().unwrap_or(command.get_name()),
+		"licer" | "licer-new"
+	);
+
+	Man::new(command.version(env!("CARGO_PKG_VERSION"))).render(out)?;
+
+	if licenses {
+		writeln!(out, ".SH LICENSES\n.nf")?;
+
+		for line in info::table(&all_licenses()).lines() {
+			writeln!(out, "{}", line.replace('\\', "\\\\").replace('-', "\\-"))?;
+		}
+
+		writeln!(out, ".fi")?;
+	}
+
+	Ok(())
+}
+
+pub fn manpages(directory: Option<&Path>) -> Result<JsonValue, LicerError> {
+	let mut command = Cli::command().disable_help_subcommand(true);
+
+	command.build();
+
+	let directory = match directory {
+		Some(directory) => directory,
+		None => {
+			return render_manpage(command, &mut stdout())
+				.map(|_| JsonValue::Null)
+				.map_err(|_| LicerError::FailedWrite {
+					path: PathBuf::from("stdout"),
+				})
+		}
+	};
+
+	let mut files = Vec::new();
+
+	for command in [command.clone()]
+		.into_iter()
+		.chain(command.get_subcommands().cloned())
+	{
+		let path = directory.join(format!(
+			"{}.1",
+			command.get_display_name().unwrap_or(command.get_name())
+		));
+
+		File::create(&path)
+			.and_then(|mut file| render_manpage(command, &mut file))
+			.map_err(|_| LicerError::FailedWrite { path: path.clone() })?;
+
+		success(format!("Wrote man page '{}'", path.to_string_lossy()));
+		files.push(path.to_string_lossy().into_owned());
+	}
+
+	Ok(object! { files: files })
+}
@@ -0,0 +1,164 @@
+use crate::{LicerError, Project};
+use std::{
+	env::var_os,
+	io::ErrorKind,
+	path::{Path, PathBuf},
+};
+use toml_edit::{Document, Item};
+
+#[derive(Default)]
+pub struct Config {
+	pub license: Option<String>,
+	pub names: Vec<String>,
+	pub emails: Vec<String>,
+	pub holders: Vec<String>,
+	pub holder_format: Option<String>,
+	pub header_style: Option<String>,
+	pub lang: Option<String>,
+	pub project: Option<String>,
+	pub url: Option<String>,
+	pub year: Option<String>,
+	pub file: Option<String>,
+	pub directory: Option<String>,
+	pub templates: Vec<PathBuf>,
+}
+
+impl Config {
+	fn merge(&mut self, other: Config) {
+		if !other.names.is_empty() {
+			self.names = other.names;
+			self.emails = other.emails;
+		} else if !other.emails.is_empty() {
+			self.emails = other.emails;
+		}
+
+		if !other.holders.is_empty() {
+			self.holders = other.holders;
+		}
+
+		self.license = other.license.or(self.license.take());
+		self.holder_format = other.holder_format.or(self.holder_format.take());
+		self.header_style = other.header_style.or(self.header_style.take());
+		self.lang = other.lang.or(self.lang.take());
+		self.project = other.project.or(self.project.take());
+		self.url = other.url.or(self.url.take());
+		self.year = other.year.or(self.year.take());
+		self.file = other.file.or(self.file.take());
+		self.directory = other.directory.or(self.directory.take());
+		self.templates.extend(other.templates);
+	}
+}
+
+pub fn user_config_path() -> Option<PathBuf> {
+	var_os("XDG_CONFIG_HOME")
+		.map(PathBuf::from)
+		.or_else(|| {
+			var_os("HOME")
+				.or_else(|| var_os("USERPROFILE"))
+				.map(|home| PathBuf::from(home).join(".config"))
+		})
+		.map(|dir| dir.join("licer").join("config.toml"))
+}
+
+pub fn project_config_path(root: &Project) -> Option<PathBuf> {
+	root.fs()
+		.absolute(root.dir())
+		.ancestors()
+		.map(|dir| dir.join(".licer.toml"))
+		.find(|path| root.fs().is_file(path))
+}
+
+fn resolve_path(config: &Path, dir: &str) -> PathBuf {
+	match dir.strip_prefix("~/") {
+		Some(dir) => var_os("HOME")
+			.or_else(|| var_os("USERPROFILE"))
+			.map(|home| PathBuf::from(home).join(dir))
+			.unwrap_or_else(|| PathBuf::from(dir)),
+		None => config.parent().unwrap_or(Path::new(".")).join(dir),
+	}
+}
+
+fn read_config_file(root: &Project, path: &Path) -> Option<Config> {
+	let kind = path.to_string_lossy().into_owned();
+
+	let toml = match root.fs().read(path) {
+		Err(err) => {
+			if err.kind() != ErrorKind::NotFound {
+				LicerError::FailedRead { kind }.warn();
+			}
+
+			return None;
+		}
+		Ok(string) => match string.parse::<Document>() {
+			Err(_) => {
+				LicerError::FailedParse { kind }.warn();
+				return None;
+			}
+			Ok(toml) => toml,
+		},
+	};
+
+	let string = |item: &Item| item.as_str().map(|string| string.to_owned());
+
+	let strings = |item: &Item| match item.as_array() {
+		Some(array) => array
+			.iter()
+			.filter_map(|value| value.as_str())
+			.map(|string| string.to_owned())
+			.collect(),
+		None => string(item).into_iter().collect(),
+	};
+
+	let mut config = Config::default();
+
+	for (key, item) in toml.iter() {
+		match key {
+			"license" => config.license = string(item),
+			"name" => config.names = strings(item),
+			"email" => config.emails = strings(item),
+			"holder" => config.holders = strings(item),
+			"holder_format" => config.holder_format = string(item),
+			"header_style" => config.header_style = string(item),
+			"lang" => config.lang = string(item),
+			"project" => config.project = string(item),
+			"url" => config.url = string(item),
+			"year" => {
+				config.year =
+					string(item).or_else(|| item.as_integer().map(|year| year.to_string()))
+			}
+			"file" => config.file = string(item),
+			"directory" => config.directory = string(item),
+			"templates" => {
+				config.templates = strings(item)
+					.iter()
+					.map(|dir| resolve_path(path, dir))
+					.collect()
+			}
+			key => LicerError::UnknownKey {
+				key: key.to_owned(),
+				kind: kind.to_owned(),
+			}
+			.warn(),
+		}
+	}
+
+	Some(config)
+}
+
+pub fn read_config(root: &Project) -> Config {
+	let mut config = Config::default();
+
+	for path in [
+		root.user_config().map(Path::to_path_buf),
+		project_config_path(root),
+	]
+	.into_iter()
+	.flatten()
+	{
+		if let Some(other) = read_config_file(root, &path) {
+			config.merge(other);
+		}
+	}
+
+	config
+}
@@ -0,0 +1,104 @@
+use crate::{licenses::*, read_manifest_license, LicerError, Project};
+use similar::TextDiff;
+use std::{
+	path::{Path, PathBuf},
+	time::Duration,
+};
+
+fn candidates() -> Vec<(&'static str, String)> {
+	all_licenses()
+		.into_iter()
+		.flat_map(|license| {
+			let mut texts = vec![license.text];
+
+			texts.extend(
+				license
+					.languages
+					.iter()
+					.map(|(_, text)| *text)
+					.filter(|text| *text != license.text),
+			);
+
+			texts.into_iter().filter_map(move |text| {
+				Some((
+					license.id,
+					license.render_text(text, |_| String::new()).ok()?,
+				))
+			})
+		})
+		.collect()
+}
+
+fn words(text: &str) -> String {
+	text.split_whitespace().collect::<Vec<&str>>().join("\n")
+}
+
+pub fn detect_license(text: &str) -> Option<&'static str> {
+	let text = words(text);
+	let len = text.len() as f32;
+
+	candidates()
+		.into_iter()
+		.filter_map(|(license, candidate)| {
+			let candidate = words(&candidate);
+			let ratio = candidate.len() as f32 / len;
+
+			if !(0.75..=1.25).contains(&ratio) {
+				return None;
+			}
+
+			Some((
+				license,
+				TextDiff::configure()
+					.timeout(Duration::from_millis(200))
+					.diff_lines(&candidate, &text)
+					.ratio(),
+			))
+		})
+		.filter(|(_, ratio)| *ratio >= 0.9)
+		.max_by(|a, b| a.1.total_cmp(&b.1))
+		.map(|(license, _)| license)
+}
+
+pub fn find_license_file(root: &Project) -> Option<PathBuf> {
+	[
+		"LICENSE",
+		"LICENSE.md",
+		"LICENSE.txt",
+		"COPYING",
+		"COPYING.LESSER",
+	]
+	.into_iter()
+	.map(PathBuf::from)
+	.find(|file| root.fs().is_file(&root.path(file)))
+}
+
+pub fn check_license(root: &Project, file: Option<&Path>) -> Result<&'static str, LicerError> {
+	let file = match file {
+		Some(file) => file.to_path_buf(),
+		None => find_license_file(root).ok_or_else(|| LicerError::MissingFile {
+			kind: "license file".to_owned(),
+		})?,
+	};
+	let kind = file.to_string_lossy().into_owned();
+
+	let text = root.read(&file).map_err(|_| LicerError::FailedRead {
+		kind: kind.to_owned(),
+	})?;
+	let found = detect_license(&text)
+		.and_then(find_license)
+		.ok_or(LicerError::UnknownText { kind })?;
+
+	let id = read_manifest_license(root).ok_or_else(|| LicerError::MissingFile {
+		kind: "license in a Cargo.toml, package.json or pyproject.toml".to_owned(),
+	})?;
+
+	if id != found.spdx {
+		return Err(LicerError::LicenseMismatch {
+			found: found.spdx.to_owned(),
+			id,
+		});
+	}
+
+	Ok(found.spdx)
+}
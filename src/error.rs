@@ -1,49 +1,198 @@
-use colored::Colorize;
+use crate::licenses::Field;
+use std::{cell::RefCell, fmt, path::PathBuf};
 
-pub enum CliError {
+thread_local! {
+	static WARNINGS: RefCell<Option<Vec<LicerError>>> = const { RefCell::new(None) };
+}
+
+pub fn collect_warnings<T>(f: impl FnOnce() -> T) -> (T, Vec<LicerError>) {
+	let outer = WARNINGS.with(|warnings| warnings.replace(Some(Vec::new())));
+	let result = f();
+	let warnings = WARNINGS.with(|warnings| warnings.replace(outer));
+
+	(result, warnings.unwrap_or_default())
+}
+
+pub fn take_warnings() -> Vec<LicerError> {
+	WARNINGS.with(|warnings| {
+		warnings
+			.borrow_mut()
+			.as_mut()
+			.map(std::mem::take)
+			.unwrap_or_default()
+	})
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LicerError {
 	UnknownArg { arg: String },
 	UnknownFlag { flag: String },
 	UnknownLicense { license: String },
+	UnknownLang { lang: String, langs: String },
+	UnresolvedPlaceholder { license: String, key: String },
+	MissingArg { arg: String, reason: String },
 	InvalidFlag { flag: String, reason: String },
 	MissingFlag { flag: String, reason: String },
-	FailedWrite { path: std::path::PathBuf },
+	MissingFields { fields: Vec<Field> },
+	FailedWrite { path: PathBuf },
+	ExistingLicense { path: PathBuf },
 	FailedRead { kind: String },
 	FailedParse { kind: String },
+	UnknownKey { key: String, kind: String },
+	DuplicateLicense { id: String, kind: String },
+	DeprecatedLicense { license: String, id: String },
+	InvalidSpdx { id: String, kind: String },
+	IgnoredHolderYears { holder: String },
+	MissingMembers,
+	MissingFile { kind: String },
+	UnknownText { kind: String },
+	LicenseMismatch { found: String, id: String },
 }
 
-impl CliError {
-	pub fn throw(&self) -> ! {
-		eprintln!(
-			"{} {}",
-			"ERROR".red().bold(),
-			match self {
-				CliError::UnknownArg { arg } => format!("Unknown argument '{}'!", arg),
-				CliError::UnknownFlag { flag } => format!("Unknown flag '{}'!", flag),
-				CliError::UnknownLicense { license } => format!("Unknown license '{}'!", license),
-				CliError::InvalidFlag { flag, reason } =>
-					format!("Invalid {} flag! {}", flag, reason),
-				CliError::MissingFlag { flag, reason } =>
-					format!("Missing {} flag! {}", flag, reason),
-				CliError::FailedWrite { path } =>
-					format!("Failed to write to '{}'!", path.to_str().unwrap()),
-				_ => unreachable!(),
-			},
-		);
-
-		std::process::exit(1);
+impl fmt::Display for LicerError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			LicerError::UnknownArg { arg } => write!(f, "Unknown argument '{}'!", arg),
+			LicerError::UnknownFlag { flag } => write!(f, "Unknown flag '{}'!", flag),
+			LicerError::UnknownLicense { license } => write!(f, "Unknown license '{}'!", license),
+			LicerError::UnknownLang { lang, langs } => write!(
+				f,
+				"The license is not available in the language '{}'! Available languages: {}",
+				lang, langs
+			),
+			LicerError::UnresolvedPlaceholder { license, key } => write!(
+				f,
+				"The license '{}' contains the unresolved placeholder '{}'!",
+				license, key
+			),
+			LicerError::MissingArg { arg, reason } =>
+				write!(f, "Missing {} argument! {}", arg, reason),
+			LicerError::InvalidFlag { flag, reason } =>
+				write!(f, "Invalid {} flag! {}", flag, reason),
+			LicerError::MissingFlag { flag, reason } =>
+				write!(f, "Missing {} flag! {}", flag, reason),
+			LicerError::MissingFields { fields } => write!(
+				f,
+				"Missing required license info! It could not be obtained via a Cargo.toml (Rust), package.json (Node.js) or git config:{}",
+				fields
+					.iter()
+					.map(|field| format!("\n  {} ({})", field.label(), field.flag()))
+					.collect::<String>()
+			),
+			LicerError::FailedWrite { path } =>
+				write!(f, "Failed to write to '{}'!", path.to_string_lossy()),
+			LicerError::ExistingLicense { path } => write!(
+				f,
+				"Refusing to overwrite '{}'! Use the --force flag to overwrite it!",
+				path.to_string_lossy()
+			),
+			LicerError::FailedRead { kind } =>
+				write!(f, "A '{}' was found but failed to be read!", kind),
+			LicerError::FailedParse { kind } =>
+				write!(f, "A '{}' was found but failed to be parsed!", kind),
+			LicerError::UnknownKey { key, kind } =>
+				write!(f, "Ignored unknown key '{}' in '{}'!", key, kind),
+			LicerError::DuplicateLicense { id, kind } => write!(
+				f,
+				"Ignored the template '{}' as a license with the id '{}' already exists!",
+				kind, id
+			),
+			LicerError::DeprecatedLicense { license, id } => write!(
+				f,
+				"The license '{}' is deprecated or not canonical, '{}' is used instead!",
+				license, id
+			),
+			LicerError::InvalidSpdx { id, kind } => write!(
+				f,
+				"The SPDX id '{}' of '{}' is not on the SPDX License List! Use a LicenseRef- id for custom licenses!",
+				id, kind
+			),
+			LicerError::IgnoredHolderYears { holder } => write!(
+				f,
+				"The years of the holder '{}' are ignored as only the lines holder format uses them!",
+				holder
+			),
+			LicerError::MissingMembers => write!(
+				f,
+				"No workspace packages were found via a Cargo.toml (Rust), package.json (Node.js) or pnpm-workspace.yaml (pnpm)!"
+			),
+			LicerError::MissingFile { kind } => write!(f, "No {} was found!", kind),
+			LicerError::UnknownText { kind } =>
+				write!(f, "The license of '{}' could not be detected!", kind),
+			LicerError::LicenseMismatch { found, id } => write!(
+				f,
+				"The license file contains '{}' but the manifest declares '{}'!",
+				found, id
+			),
+		}
+	}
+}
+
+impl std::error::Error for LicerError {}
+
+impl LicerError {
+	pub fn kind(&self) -> &'static str {
+		match self {
+			LicerError::UnknownArg { .. } => "UnknownArg",
+			LicerError::UnknownFlag { .. } => "UnknownFlag",
+			LicerError::UnknownLicense { .. } => "UnknownLicense",
+			LicerError::UnknownLang { .. } => "UnknownLang",
+			LicerError::UnresolvedPlaceholder { .. } => "UnresolvedPlaceholder",
+			LicerError::MissingArg { .. } => "MissingArg",
+			LicerError::InvalidFlag { .. } => "InvalidFlag",
+			LicerError::MissingFlag { .. } => "MissingFlag",
+			LicerError::MissingFields { .. } => "MissingFields",
+			LicerError::FailedWrite { .. } => "FailedWrite",
+			LicerError::ExistingLicense { .. } => "ExistingLicense",
+			LicerError::FailedRead { .. } => "FailedRead",
+			LicerError::FailedParse { .. } => "FailedParse",
+			LicerError::UnknownKey { .. } => "UnknownKey",
+			LicerError::DuplicateLicense { .. } => "DuplicateLicense",
+			LicerError::DeprecatedLicense { .. } => "DeprecatedLicense",
+			LicerError::IgnoredHolderYears { .. } => "IgnoredHolderYears",
+			LicerError::InvalidSpdx { .. } => "InvalidSpdx",
+			LicerError::MissingMembers => "MissingMembers",
+			LicerError::MissingFile { .. } => "MissingFile",
+			LicerError::UnknownText { .. } => "UnknownText",
+			LicerError::LicenseMismatch { .. } => "LicenseMismatch",
+		}
+	}
+
+	pub fn exit_code(&self) -> i32 {
+		match self {
+			LicerError::ExistingLicense { .. } => 1,
+			LicerError::UnknownArg { .. }
+			| LicerError::UnknownFlag { .. }
+			| LicerError::MissingArg { .. }
+			| LicerError::InvalidFlag { .. }
+			| LicerError::MissingFlag { .. }
+			| LicerError::IgnoredHolderYears { .. } => 2,
+			LicerError::UnknownLicense { .. }
+			| LicerError::UnknownLang { .. }
+			| LicerError::UnknownText { .. }
+			| LicerError::LicenseMismatch { .. } => 3,
+			LicerError::UnresolvedPlaceholder { .. }
+			| LicerError::MissingFields { .. }
+			| LicerError::MissingMembers
+			| LicerError::MissingFile { .. } => 4,
+			LicerError::FailedWrite { .. } | LicerError::FailedRead { .. } => 5,
+			LicerError::FailedParse { .. }
+			| LicerError::UnknownKey { .. }
+			| LicerError::DuplicateLicense { .. }
+			| LicerError::DeprecatedLicense { .. }
+			| LicerError::InvalidSpdx { .. } => 6,
+		}
 	}
 
 	pub fn warn(&self) {
-		eprintln!(
-			"{} {}",
-			"WARN".yellow().bold(),
-			match self {
-				CliError::FailedRead { kind } =>
-					format!("A '{}' was found but failed to be read!", kind),
-				CliError::FailedParse { kind } =>
-					format!("A '{}' was found but failed to be parsed!", kind),
-				_ => unreachable!(),
+		WARNINGS.with(|warnings| {
+			if let Some(warnings) = warnings
+				.borrow_mut()
+				.as_mut()
+				.filter(|warnings| !warnings.contains(self))
+			{
+				warnings.push(self.clone());
 			}
-		);
+		});
 	}
 }
@@ -0,0 +1,155 @@
+use crate::{
+	licenses::{find_license, render, Field},
+	request::canonical_id,
+	resolve_values, LicerError, Project, Values,
+};
+use std::path::Path;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CommentStyle {
+	Slash,
+	Hash,
+	Block,
+	Dash,
+	Html,
+}
+
+impl CommentStyle {
+	pub fn parse(style: &str) -> Option<CommentStyle> {
+		match style {
+			"slash" => Some(CommentStyle::Slash),
+			"hash" => Some(CommentStyle::Hash),
+			"block" => Some(CommentStyle::Block),
+			"dash" => Some(CommentStyle::Dash),
+			"html" => Some(CommentStyle::Html),
+			_ => None,
+		}
+	}
+
+	pub fn for_path(path: &Path) -> CommentStyle {
+		let extension = path
+			.extension()
+			.map(|extension| extension.to_string_lossy().to_lowercase())
+			.unwrap_or_default();
+
+		match extension.as_str() {
+			"py" | "rb" | "sh" | "bash" | "zsh" | "fish" | "pl" | "r" | "toml" | "yaml" | "yml"
+			| "ex" | "exs" | "nix" | "ps1" | "cmake" => CommentStyle::Hash,
+			"css" | "scss" | "less" => CommentStyle::Block,
+			"sql" | "lua" | "hs" | "elm" | "ada" => CommentStyle::Dash,
+			"html" | "htm" | "xml" | "svg" | "md" | "vue" => CommentStyle::Html,
+			_ => CommentStyle::Slash,
+		}
+	}
+
+	fn comment(&self, lines: &[String]) -> String {
+		let prefix = |prefix: &str| {
+			lines
+				.iter()
+				.map(|line| format!("{} {}\n", prefix, line))
+				.collect::<String>()
+		};
+
+		match self {
+			CommentStyle::Slash => prefix("//"),
+			CommentStyle::Hash => prefix("#"),
+			CommentStyle::Dash => prefix("--"),
+			CommentStyle::Block => format!("/*\n{}*/\n", prefix(" *")),
+			CommentStyle::Html => format!("<!--\n{}-->\n", prefix(" ")),
+		}
+	}
+}
+
+fn header_spdx(license: &str) -> Result<String, LicerError> {
+	let expression = license.replace('(', " ( ").replace(')', " ) ");
+	let tokens = expression.split_whitespace().collect::<Vec<_>>();
+
+	let ids = tokens
+		.iter()
+		.enumerate()
+		.map(|(i, token)| match *token {
+			"(" | ")" | "AND" | "OR" | "WITH" => Ok(token.to_string()),
+			exception if i > 0 && tokens[i - 1] == "WITH" => Ok(exception.to_owned()),
+			id => find_license(&canonical_id(id))
+				.map(|license| license.spdx.to_owned())
+				.ok_or_else(|| LicerError::UnknownLicense {
+					license: id.to_owned(),
+				}),
+		})
+		.collect::<Result<Vec<String>, LicerError>>()?;
+
+	if ids.is_empty() {
+		return Err(LicerError::UnknownLicense {
+			license: license.to_owned(),
+		});
+	}
+
+	Ok(ids.join(" ").replace("( ", "(").replace(" )", ")"))
+}
+
+pub fn render_header(
+	root: &Project,
+	license: &str,
+	values: Values,
+	style: CommentStyle,
+) -> Result<String, LicerError> {
+	let spdx = header_spdx(license)?;
+	let fields = [Field::Year, Field::Names];
+	let values = resolve_values(root, &fields, values)?;
+
+	let copyright = render(
+		&values.expand_lines("SPDX-FileCopyrightText: {{year}} {{holders}}"),
+		|key| {
+			fields
+				.iter()
+				.find(|field| field.key() == key)
+				.map(|field| values.get(*field).unwrap_or_default())
+		},
+	)
+	.map_err(|key| LicerError::UnresolvedPlaceholder {
+		license: license.to_owned(),
+		key,
+	})?;
+
+	let mut lines = copyright
+		.lines()
+		.map(|line| line.to_owned())
+		.collect::<Vec<String>>();
+
+	lines.push(format!("SPDX-License-Identifier: {}", spdx));
+
+	Ok(style.comment(&lines))
+}
+
+pub fn insert_header(root: &Project, path: &Path, header: &str) -> Result<bool, LicerError> {
+	let path = root.path(path);
+	let kind = path.to_string_lossy().into_owned();
+
+	let text = root
+		.fs()
+		.read(&path)
+		.map_err(|_| LicerError::FailedRead { kind })?;
+
+	if text.contains("SPDX-License-Identifier:") {
+		return Ok(false);
+	}
+
+	let (shebang, rest) = match text.starts_with("#!") {
+		true => match text.split_once('\n') {
+			Some((shebang, rest)) => (format!("{}\n", shebang), rest),
+			None => (format!("{}\n", text), ""),
+		},
+		false => (String::new(), text.as_str()),
+	};
+
+	let separator = if rest.is_empty() { "" } else { "\n" };
+
+	root.fs()
+		.write(
+			&path,
+			&format!("{}{}{}{}", shebang, header, separator, rest),
+		)
+		.map_err(|_| LicerError::FailedWrite { path: path.clone() })?;
+
+	Ok(true)
+}
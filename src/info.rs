@@ -0,0 +1,268 @@
+use crate::{cli::ListArgs, json, quiet, throw};
+use colored::Colorize;
+use json::{object, JsonValue};
+use licer::{all_licenses, find_license, search_licenses, Field, Kind, License, LicerError, Rule};
+
+fn find(id: &str) -> &'static License {
+	find_license(id).unwrap_or_else(|| {
+		throw(LicerError::UnknownLicense {
+			license: id.to_owned(),
+		})
+	})
+}
+
+fn fields(license: &License) -> String {
+	if license.fields.is_empty() {
+		"None".to_owned()
+	} else {
+		license
+			.fields
+			.iter()
+			.map(|field| field.label())
+			.collect::<Vec<&str>>()
+			.join(", ")
+	}
+}
+
+fn license_json(license: &License) -> JsonValue {
+	let labels = |rules: &[Rule]| rules.iter().map(|rule| rule.label()).collect::<Vec<&str>>();
+
+	object! {
+		id: license.id,
+		name: license.name,
+		spdx: license.spdx,
+		osi_approved: license.osi_approved(),
+		fsf_libre: license.fsf_libre(),
+		fields: license.fields.iter().map(|field| field.key()).collect::<Vec<&str>>(),
+		permissions: labels(license.permissions),
+		conditions: labels(license.conditions),
+		limitations: labels(license.limitations),
+	}
+}
+
+pub fn table(licenses: &[&License]) -> String {
+	let width = |column: fn(&License) -> String, title: &str| {
+		licenses
+			.iter()
+			.map(|license| column(license).chars().count())
+			.chain([title.len()])
+			.max()
+			.unwrap_or_default()
+			+ 4
+	};
+	let id_width = width(|license| license.id.to_owned(), "Licer Name");
+	let name_width = width(|license| license.name.to_owned(), "Full Name");
+
+	let mut table = format!(
+		"{:<id_width$}{:<name_width$}Info Required\n{}",
+		"Licer Name",
+		"Full Name",
+		"-".repeat(id_width + name_width + 40),
+		id_width = id_width,
+		name_width = name_width
+	);
+
+	for license in licenses.iter() {
+		table.push_str(&format!(
+			"\n{:<id_width$}{:<name_width$}{}",
+			license.id,
+			license.name,
+			fields(license),
+			id_width = id_width,
+			name_width = name_width
+		));
+	}
+
+	table
+}
+
+fn report(licenses: Vec<&'static License>, empty: &str) -> JsonValue {
+	if !json() {
+		match licenses.is_empty() {
+			true if quiet() => (),
+			true => outln!("{} {}", "INFO".blue().bold(), empty),
+			false => outln!("{}", table(&licenses)),
+		}
+	}
+
+	object! {
+		licenses: licenses.into_iter().map(license_json).collect::<Vec<JsonValue>>(),
+	}
+}
+
+pub fn list(args: ListArgs) -> JsonValue {
+	let fields = args
+		.requires
+		.iter()
+		.map(|name| {
+			Field::parse(name).unwrap_or_else(|| {
+				throw(LicerError::InvalidFlag {
+					flag: "requires".to_owned(),
+					reason: format!(
+						"The provided info '{}' is not one of year, name, email, project or url!",
+						name
+					),
+				})
+			})
+		})
+		.collect::<Vec<Field>>();
+
+	let licenses = all_licenses()
+		.into_iter()
+		.filter(|license| match license.kind() {
+			Kind::Permissive | Kind::PublicDomain => !args.copyleft,
+			Kind::Copyleft | Kind::WeakCopyleft => !args.permissive,
+			Kind::Proprietary | Kind::Unknown => !args.permissive && !args.copyleft,
+		})
+		.filter(|license| !args.osi || license.osi_approved())
+		.filter(|license| !args.fsf_libre || license.fsf_libre())
+		.filter(|license| fields.iter().all(|field| license.fields.contains(field)))
+		.collect();
+
+	report(licenses, "No license matches every filter")
+}
+
+pub fn search(term: &str) -> JsonValue {
+	report(
+		search_licenses(term),
+		&format!("No license matches '{}'", term),
+	)
+}
+
+pub fn info(id: &str) -> JsonValue {
+	let license = find(id);
+
+	if json() {
+		return license_json(license);
+	}
+
+	outln!(
+		"\n{} ({})\n\nSPDX ID        {}\nInfo Required  {}",
+		license.name.bold(),
+		license.id,
+		license.spdx,
+		fields(license)
+	);
+
+	for (title, rules, limitation) in [
+		("Permissions", license.permissions, false),
+		("Conditions", license.conditions, false),
+		("Limitations", license.limitations, true),
+	] {
+		outln!("\n{}", title.bold());
+
+		if license.kind() == Kind::Unknown {
+			outln!("  Unknown");
+		} else if rules.is_empty() {
+			outln!("  None");
+		}
+
+		for rule in rules {
+			let mark = match title {
+				"Permissions" => "✓".green(),
+				"Conditions" => "ℹ".blue(),
+				_ => "✗".red(),
+			};
+
+			outln!(
+				"  {} {:<41}{}",
+				mark,
+				rule.label(),
+				rule.description(limitation)
+			);
+		}
+	}
+
+	license_json(license)
+}
+
+pub fn compare(ids: &[String]) -> JsonValue {
+	let licenses = ids.iter().map(|id| find(id)).collect::<Vec<&License>>();
+
+	let report = object! {
+		licenses: licenses.iter().map(|license| license_json(license)).collect::<Vec<JsonValue>>(),
+	};
+
+	if json() {
+		return report;
+	}
+
+	let width = licenses
+		.iter()
+		.map(|license| license.name.chars().count().max(fields(license).len()))
+		.max()
+		.unwrap_or_default()
+		+ 2;
+
+	let rows = |title: &str, values: Vec<String>| {
+		let mut row = format!("{:<42}", title);
+
+		for value in values {
+			row.push_str(&format!("{:<width$}", value, width = width));
+		}
+
+		outln!("{}", row.trim_end());
+	};
+
+	outln!();
+	rows(
+		"",
+		licenses
+			.iter()
+			.map(|license| license.id.to_owned())
+			.collect(),
+	);
+	rows(
+		"Full Name",
+		licenses
+			.iter()
+			.map(|license| license.name.to_owned())
+			.collect(),
+	);
+	rows(
+		"SPDX ID",
+		licenses
+			.iter()
+			.map(|license| license.spdx.to_owned())
+			.collect(),
+	);
+	rows(
+		"Info Required",
+		licenses.iter().map(|license| fields(license)).collect(),
+	);
+
+	let section = |rules: fn(&License) -> &'static [Rule]| {
+		licenses
+			.iter()
+			.map(|license| rules(license))
+			.collect::<Vec<&[Rule]>>()
+	};
+
+	for (title, rules) in [
+		("Permissions", section(|license| license.permissions)),
+		("Conditions", section(|license| license.conditions)),
+		("Limitations", section(|license| license.limitations)),
+	] {
+		outln!("\n{}", title.bold());
+
+		for rule in Rule::ALL
+			.iter()
+			.filter(|rule| rules.iter().any(|rules| rules.contains(rule)))
+		{
+			rows(
+				&format!("  {}", rule.label()),
+				rules
+					.iter()
+					.zip(&licenses)
+					.map(|(rules, license)| match rules.contains(rule) {
+						true => "✓".to_owned(),
+						false if license.kind() == Kind::Unknown => "?".to_owned(),
+						false => "-".to_owned(),
+					})
+					.collect(),
+			);
+		}
+	}
+
+	report
+}
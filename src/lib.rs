@@ -1,17 +1,41 @@
-use colored::Colorize;
-pub use error::CliError;
+pub use config::{project_config_path, read_config, user_config_path, Config};
+pub use detect::{check_license, detect_license, find_license_file};
+pub use error::{collect_warnings, take_warnings, LicerError};
+pub use header::{insert_header, render_header, CommentStyle};
 use json::parse;
+pub use licenses::{
+	all_licenses, find_license, is_valid_spdx, normalize_spdx, register_license, Field, Gnu, Kind,
+	License, Rule, LICENSES,
+};
+pub use project::{Disk, Fs, Git, Memory, NoVcs, Project, Vcs};
 use regex::Regex;
+pub use request::{GeneratedLicense, LicenseRequest};
+use similar::TextDiff;
 use std::{
-	fs::{read_to_string, write},
 	io::ErrorKind,
-	path::PathBuf,
+	path::{Path, PathBuf},
 	time::{SystemTime, UNIX_EPOCH},
 };
-use toml_edit::{value, Document};
+pub use template::{load_template, load_templates, parse_template};
+use toml_edit::{value, Array, Document, InlineTable, Item, Table};
+use workspace::{cargo_members, find_cargo_root, find_members, is_inherited};
+mod config;
+mod detect;
 mod error;
+mod header;
 mod licenses;
+mod project;
+mod request;
+mod template;
+mod workspace;
+
+pub struct ManifestUpdate {
+	pub path: PathBuf,
+	pub license: String,
+	pub diff: String,
+}
 
+#[derive(Clone)]
 pub struct Author {
 	pub name: String,
 	pub email: Option<String>,
@@ -23,14 +47,14 @@ pub struct PackageInfo {
 	pub url: Option<String>,
 }
 
-pub fn read_node(extract_regex: &Regex) -> Option<PackageInfo> {
-	match read_to_string("package.json") {
+pub fn read_node(root: &Project, extract_regex: &Regex) -> Option<PackageInfo> {
+	match root.read("package.json") {
 		Err(err) => {
 			if err.kind() == ErrorKind::NotFound {
 				return None;
 			}
 
-			CliError::FailedRead {
+			LicerError::FailedRead {
 				kind: "package.json".to_owned(),
 			}
 			.warn();
@@ -39,7 +63,7 @@ pub fn read_node(extract_regex: &Regex) -> Option<PackageInfo> {
 		}
 		Ok(string) => match parse(&string) {
 			Err(_) => {
-				CliError::FailedParse {
+				LicerError::FailedParse {
 					kind: "package.json".to_owned(),
 				}
 				.warn();
@@ -94,12 +118,8 @@ pub fn read_node(extract_regex: &Regex) -> Option<PackageInfo> {
 
 				if let json::JsonValue::Array(contributors) = &json["contributors"] {
 					if !contributors.is_empty() {
-						pkg.authors.append(
-							&mut contributors
-								.iter()
-								.filter_map(|contributor| extract_info(contributor))
-								.collect(),
-						)
+						pkg.authors
+							.append(&mut contributors.iter().filter_map(extract_info).collect())
 					}
 				}
 
@@ -114,7 +134,7 @@ pub fn read_node(extract_regex: &Regex) -> Option<PackageInfo> {
 }
 
 fn failed_parse<T>() -> Option<T> {
-	CliError::FailedParse {
+	LicerError::FailedParse {
 		kind: "Cargo.toml".to_owned(),
 	}
 	.warn();
@@ -122,14 +142,14 @@ fn failed_parse<T>() -> Option<T> {
 	None
 }
 
-pub fn read_cargo(extract_regex: &Regex) -> Option<PackageInfo> {
-	match read_to_string("Cargo.toml") {
+pub fn read_cargo(root: &Project, extract_regex: &Regex) -> Option<PackageInfo> {
+	match root.read("Cargo.toml") {
 		Err(err) => {
 			if err.kind() == ErrorKind::NotFound {
 				return None;
 			}
 
-			CliError::FailedRead {
+			LicerError::FailedRead {
 				kind: "Cargo.toml".to_owned(),
 			}
 			.warn();
@@ -139,14 +159,33 @@ pub fn read_cargo(extract_regex: &Regex) -> Option<PackageInfo> {
 		Ok(string) => match string.parse::<Document>() {
 			Err(_) => failed_parse(),
 			Ok(toml) => {
-				let toml = toml.get("package").or_else(failed_parse)?;
+				let root = if toml.contains_key("workspace") {
+					None
+				} else {
+					find_cargo_root(root)
+				};
+
+				let package = toml.get("package");
+				let workspace = toml
+					.get("workspace")
+					.or_else(|| root.as_ref()?.get("workspace"))
+					.and_then(|workspace| workspace.get("package"));
+
+				if package.is_none() && workspace.is_none() {
+					return failed_parse();
+				}
+
+				let field = |key: &str| match package {
+					Some(package) => match package.get(key) {
+						Some(item) if is_inherited(item) => workspace?.get(key),
+						item => item,
+					},
+					None => workspace?.get(key),
+				};
 
 				Some(PackageInfo {
-					name: toml
-						.get("name")
-						.and_then(|name| Some(name.as_str()?.to_owned())),
-					authors: toml
-						.get("authors")
+					name: field("name").and_then(|name| Some(name.as_str()?.to_owned())),
+					authors: field("authors")
 						.and_then(|authors| {
 							Some(
 								authors
@@ -181,17 +220,15 @@ pub fn read_cargo(extract_regex: &Regex) -> Option<PackageInfo> {
 							)
 						})
 						.unwrap_or_default(),
-					url: toml
-						.get("homepage")
-						.and_then(|url| Some(url.as_str()?.to_owned())),
+					url: field("homepage").and_then(|url| Some(url.as_str()?.to_owned())),
 				})
 			}
 		},
 	}
 }
 
-pub fn read_python(extract_regex: &Regex) -> Option<PackageInfo> {
-	read_cargo(extract_regex)
+pub fn read_python(root: &Project, extract_regex: &Regex) -> Option<PackageInfo> {
+	read_cargo(root, extract_regex)
 }
 
 pub fn read_git(stdout: String) -> Option<Author> {
@@ -218,353 +255,821 @@ pub fn read_git(stdout: String) -> Option<Author> {
 	}
 }
 
-fn get_info(
-	emails: Vec<String>,
-	mut names: Vec<String>,
-	project: Option<String>,
-	url: Option<String>,
-	needs: (bool, bool, bool),
-) -> PackageInfo {
-	if !names.is_empty()
-		&& (
-			project.is_some() && needs.0,
-			url.is_some() && needs.1,
-			!emails.is_empty() && needs.2,
-		) == needs
-	{
-		return PackageInfo {
-			name: project,
-			authors: names
-				.drain(0..)
-				.enumerate()
-				.map(|(i, name)| Author {
-					name,
-					email: emails.get(i).map(|email| email.to_owned()),
-				})
-				.collect(),
-			url,
-		};
-	}
-
+fn read_manifests(root: &Project) -> (PackageInfo, &'static str) {
 	let extract_regex =
 		Regex::new(r"(?P<name>[^<>()\s]+)|(?:<(?P<email>.+?)>)|(?:\(.+?\))").unwrap();
 
-	let mut pkg = read_node(&extract_regex)
-		.or_else(|| read_cargo(&extract_regex))
-		.or_else(|| {
-			Some(PackageInfo {
+	read_node(root, &extract_regex)
+		.map(|pkg| (pkg, "package.json"))
+		.or_else(|| read_cargo(root, &extract_regex).map(|pkg| (pkg, "Cargo.toml")))
+		.unwrap_or((
+			PackageInfo {
 				name: None,
 				authors: Vec::new(),
 				url: None,
-			})
-		})
-		.unwrap();
+			},
+			"manifest",
+		))
+}
+
+fn read_git_config(root: &Project) -> Option<Author> {
+	read_git(root.git_config()?)
+}
 
-	drop(extract_regex);
+pub fn read_manifest_license(root: &Project) -> Option<String> {
+	let license = if let Ok(string) = root.read("package.json") {
+		parse(&string).ok()?["license"].as_str()?.to_owned()
+	} else if let Ok(string) = root.read("Cargo.toml") {
+		let toml = string.parse::<Document>().ok()?;
+
+		match toml
+			.get("package")
+			.and_then(|package| package.get("license"))
+		{
+			Some(license) if is_inherited(license) => find_cargo_root(root)?
+				.get("workspace")?
+				.get("package")?
+				.get("license")?
+				.as_str()?
+				.to_owned(),
+			Some(license) => license.as_str()?.to_owned(),
+			None => toml
+				.get("workspace")?
+				.get("package")?
+				.get("license")?
+				.as_str()?
+				.to_owned(),
+		}
+	} else {
+		let toml = root.read("pyproject.toml").ok()?.parse::<Document>().ok()?;
+		let license = toml.get("project")?.get("license")?;
+
+		license
+			.as_str()
+			.or_else(|| license.get("text")?.as_str())?
+			.to_owned()
+	};
+
+	if license == "UNLICENSED" {
+		return Some(licenses::proprietary::LICENSE.spdx.to_owned());
+	}
+
+	if license.starts_with("LicenseRef-") {
+		return Some(license);
+	}
+
+	let normalized = normalize_spdx(&license)?;
+
+	if normalized != license {
+		LicerError::DeprecatedLicense {
+			license,
+			id: normalized.to_owned(),
+		}
+		.warn();
+	}
+
+	Some(normalized.to_owned())
+}
+
+pub fn find_info(root: &Project) -> PackageInfo {
+	let (mut pkg, _) = read_manifests(root);
 
 	if pkg.authors.is_empty() {
-		if !names.is_empty() {
-			pkg.authors = names
-				.drain(0..)
+		pkg.authors = read_git_config(root).into_iter().collect();
+	}
+
+	pkg
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HolderFormat {
+	List,
+	Lines,
+	EtAl(usize),
+}
+
+impl HolderFormat {
+	pub fn parse(format: &str) -> Option<HolderFormat> {
+		match format.split_once('=') {
+			None if format == "list" => Some(HolderFormat::List),
+			None if format == "lines" => Some(HolderFormat::Lines),
+			None if format == "et-al" => Some(HolderFormat::EtAl(1)),
+			Some(("et-al", after)) => after
+				.parse()
+				.ok()
+				.filter(|after| *after > 0)
+				.map(HolderFormat::EtAl),
+			_ => None,
+		}
+	}
+}
+
+fn split_holder(holder: &str) -> (Option<&str>, &str) {
+	match Regex::new(r"^(\d{4}(?:\s*[-,]\s*\d{4})*)\s+(.+)$")
+		.unwrap()
+		.captures(holder)
+	{
+		Some(caps) => (
+			Some(caps.get(1).unwrap().as_str()),
+			caps.get(2).unwrap().as_str(),
+		),
+		None => (None, holder),
+	}
+}
+
+#[derive(Clone)]
+pub struct Values {
+	pub year: Option<String>,
+	pub authors: Vec<Author>,
+	pub holders: Vec<String>,
+	pub holder_format: HolderFormat,
+	pub lang: Option<String>,
+	pub project: Option<String>,
+	pub url: Option<String>,
+	pub sources: Vec<(Field, String)>,
+}
+
+impl Values {
+	pub fn from_flags(
+		emails: Vec<String>,
+		names: Vec<String>,
+		project: Option<String>,
+		url: Option<String>,
+		year: Option<String>,
+	) -> Values {
+		Values {
+			year,
+			authors: names
+				.into_iter()
 				.enumerate()
 				.map(|(i, name)| Author {
 					name,
 					email: emails.get(i).map(|email| email.to_owned()),
 				})
-				.collect();
-		} else {
-			pkg.authors = match std::process::Command::new("git")
-				.args(["config", "--get-regexp", "name|email"])
-				.output()
-			{
-				Err(_) => None,
-				Ok(output) => match String::from_utf8(output.stdout) {
-					Err(_) => None,
-					Ok(stdout) => read_git(stdout),
-				},
-			}
-			.map(|author| vec![author])
-			.unwrap_or(pkg.authors)
+				.collect(),
+			holders: Vec::new(),
+			holder_format: HolderFormat::List,
+			lang: None,
+			project,
+			url,
+			sources: Vec::new(),
 		}
 	}
 
-	if pkg.name.is_none() {
-		pkg.name = project;
+	fn holder_names(&self) -> Vec<&str> {
+		if self.holders.is_empty() {
+			self.authors
+				.iter()
+				.map(|author| author.name.as_str())
+				.collect()
+		} else {
+			self.holders
+				.iter()
+				.map(|holder| split_holder(holder).1)
+				.collect()
+		}
 	}
 
-	if pkg.url.is_none() {
-		pkg.url = url;
+	pub fn expand_lines(&self, template: &str) -> String {
+		if self.holder_format != HolderFormat::Lines
+			|| self.holders.is_empty() && self.authors.len() < 2
+		{
+			return template.to_owned();
+		}
+
+		let holders = if self.holders.is_empty() {
+			self.authors
+				.iter()
+				.map(|author| (None, author.name.as_str()))
+				.collect::<Vec<_>>()
+		} else {
+			self.holders
+				.iter()
+				.map(|holder| split_holder(holder))
+				.collect()
+		};
+
+		template
+			.split('\n')
+			.map(|line| {
+				if !line.contains("{{year}}") || !line.contains("{{holders}}") {
+					return line.to_owned();
+				}
+
+				holders
+					.iter()
+					.map(|(year, name)| {
+						line.replacen(
+							"{{year}}",
+							year.or(self.year.as_deref()).unwrap_or("{{year}}"),
+							1,
+						)
+						.replacen("{{holders}}", name, 1)
+					})
+					.collect::<Vec<String>>()
+					.join("\n")
+			})
+			.collect::<Vec<String>>()
+			.join("\n")
 	}
 
-	if needs.0 && pkg.name.is_none() {
-		CliError::MissingFlag {
-			flag: "-p or --project".to_owned(),
-			reason: "The project name could not be obtained via a Cargo.toml (Rust) or package.json (Node.js)!".to_owned()
+	pub fn get(&self, field: Field) -> Option<String> {
+		let join = |values: Vec<&str>| (!values.is_empty()).then(|| values.join(", "));
+
+		match field {
+			Field::Year => self.year.to_owned(),
+			Field::Names => match self.holder_format {
+				HolderFormat::EtAl(after) if self.holder_names().len() > after => Some(format!(
+					"{} et al.",
+					self.holder_names()[..after].join(", ")
+				)),
+				_ => join(self.holder_names()),
+			},
+			Field::Emails => join(
+				self.authors
+					.iter()
+					.filter_map(|author| author.email.as_deref())
+					.collect(),
+			),
+			Field::Project => self.project.to_owned(),
+			Field::Url => self.url.to_owned(),
 		}
-		.throw()
-	} else if needs.1 && pkg.url.is_none() {
-		CliError::MissingFlag {
-			flag: "-u or --url".to_owned(),
-			reason:
-				"The url could not be obtained via a Cargo.toml (Rust) or package.json (Node.js)!"
-					.to_owned(),
+	}
+
+	fn missing(&self, fields: &[Field]) -> Vec<Field> {
+		fields
+			.iter()
+			.copied()
+			.filter(|field| self.get(*field).is_none())
+			.collect()
+	}
+
+	pub fn add_source(&mut self, fields: &[Field], source: &str) {
+		for field in fields {
+			if self.get(*field).is_some() && !self.sources.iter().any(|(key, _)| key == field) {
+				self.sources.push((*field, source.to_owned()));
+			}
 		}
-		.throw()
-	} else if pkg.authors.is_empty() {
-		CliError::MissingFlag {
-			flag: "-n or --name".to_owned(),
-			reason: "The name(s) could not be obtained via a Cargo.toml (Rust), package.json (Node.js) or git config!".to_owned()
+	}
+
+	fn merge(&mut self, pkg: PackageInfo) {
+		if self.authors.is_empty() {
+			self.authors = pkg.authors;
 		}
-		.throw()
-	} else if needs.2 && !pkg.authors.iter().any(|author| author.email.is_some()) {
-		CliError::MissingFlag {
-			flag: "-e or --email".to_owned(),
-			reason:
-				"There must be at least one email to accompany the name(s)! Either provide it via the flag, in a Cargo.toml (Rust), or in a package.json (Node.js)!"
-				.to_owned(),
+
+		self.project = self.project.take().or(pkg.name);
+		self.url = self.url.take().or(pkg.url);
+	}
+}
+
+pub fn resolve_values(
+	root: &Project,
+	fields: &[Field],
+	mut values: Values,
+) -> Result<Values, LicerError> {
+	if values.year.is_none() && fields.contains(&Field::Year) {
+		values.year = Some(current_year()?);
+		values.add_source(&[Field::Year], "system time");
+	}
+
+	let missing = values.missing(fields);
+
+	if !missing.is_empty() {
+		let (pkg, source) = read_manifests(root);
+
+		values.merge(pkg);
+		values.add_source(&missing, source);
+	}
+
+	let missing = values.missing(fields);
+
+	if values.authors.is_empty() && !missing.is_empty() {
+		values.authors = read_git_config(root).into_iter().collect();
+		values.add_source(&missing, "git config");
+	}
+
+	let missing = values.missing(fields);
+
+	if !missing.is_empty() {
+		return Err(LicerError::MissingFields { fields: missing });
+	}
+
+	if fields.contains(&Field::Names) && values.holder_format != HolderFormat::Lines {
+		for holder in values
+			.holders
+			.iter()
+			.filter(|holder| split_holder(holder).0.is_some())
+		{
+			LicerError::IgnoredHolderYears {
+				holder: holder.to_owned(),
 			}
-		.throw()
+			.warn();
+		}
 	}
 
-	pkg
+	Ok(values)
+}
+
+pub fn current_year() -> Result<String, LicerError> {
+	let secs = SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map_err(|_| LicerError::MissingFlag {
+			flag: "-y or --year".to_owned(),
+			reason: "The current year could not be obtained from the system time!".to_owned(),
+		})?
+		.as_secs();
+
+	Ok((secs / 31_534_000 + 1970).to_string())
+}
+
+pub fn narrow_licenses(
+	kind: Option<Kind>,
+	patent_grant: Option<bool>,
+	network_use: Option<bool>,
+	attribution: Option<bool>,
+) -> Vec<&'static License> {
+	LICENSES
+		.into_iter()
+		.filter(|license| match (kind, license.kind()) {
+			(Some(Kind::Permissive), Kind::PublicDomain) => true,
+			(Some(kind), license_kind) => kind == license_kind,
+			(None, license_kind) => license_kind != Kind::Proprietary,
+		})
+		.filter(|license| patent_grant.is_none_or(|patent| patent == license.patent_grant()))
+		.filter(|license| network_use.is_none_or(|network| network == license.network_use()))
+		.filter(|license| {
+			attribution.is_none_or(|attribution| attribution == license.attribution())
+		})
+		.collect()
+}
+
+pub fn search_licenses(term: &str) -> Vec<&'static License> {
+	let mut licenses = all_licenses()
+		.into_iter()
+		.filter_map(|license| Some((license.rank(term)?, license)))
+		.collect::<Vec<_>>();
+
+	licenses.sort_by_key(|(rank, _)| *rank);
+	licenses.into_iter().map(|(_, license)| license).collect()
 }
 
 pub fn get_license(
+	root: &Project,
 	license: &str,
 	emails: Vec<String>,
 	names: Vec<String>,
 	project: Option<String>,
 	url: Option<String>,
 	year: Option<String>,
-) -> (String, &'static str) {
-	let year = year.unwrap_or_else(|| {
-		(SystemTime::now()
-			.duration_since(UNIX_EPOCH)
-			.unwrap_or_else(|_| {
-				CliError::MissingFlag {
-					flag: "-y or --year".to_owned(),
-					reason: "The current year could not be obtained from the system time!"
-						.to_owned(),
-				}
-				.throw()
-			})
-			.as_secs() / 31_534_000
-			+ 1970)
-			.to_string()
-	});
+) -> Result<(String, &'static str), LicerError> {
+	render_license(
+		root,
+		license,
+		Values::from_flags(emails, names, project, url, year),
+	)
+}
 
-	fn drain_names(
-		emails: Vec<String>,
-		names: Vec<String>,
-		project: Option<String>,
-		url: Option<String>,
-	) -> Vec<String> {
-		get_info(emails, names, project, url, (false, false, false))
-			.authors
-			.drain(0..)
-			.map(|author| author.name)
-			.collect()
-	}
+pub fn render_license(
+	root: &Project,
+	license: &str,
+	values: Values,
+) -> Result<(String, &'static str), LicerError> {
+	let license = licenses::find_license(license).ok_or_else(|| LicerError::UnknownLicense {
+		license: license.to_owned(),
+	})?;
 
-	match license {
-		"afl" => (licenses::afl::get(), "AFL-3.0"),
-		"agpl" => (licenses::agpl::get(), "AGPL-3.0-only"),
-		"apache_two" => (licenses::apache_two::get(), "Apache-2.0"),
-		"art" => (licenses::art::get(), "Artistic-2.0"),
-		"boost" => (licenses::boost::get(), "BSL-1.0"),
-		"bsd_clear" => (
-			licenses::bsd_clear::get(year, drain_names(emails, names, project, url)),
-			"BSD-3-Clause-Clear",
-		),
-		"bsd_four" => (
-			licenses::bsd_four::get(year, drain_names(emails, names, project, url)),
-			"BSD-4-Clause",
-		),
-		"bsd_three" => (
-			licenses::bsd_three::get(year, drain_names(emails, names, project, url)),
-			"BSD-3-Clause",
-		),
-		"bsd_two" => (
-			licenses::bsd_two::get(year, drain_names(emails, names, project, url)),
-			"BSD-2-Clause",
-		),
-		"bsd_zero" => (
-			licenses::bsd_zero::get(year, drain_names(emails, names, project, url)),
-			"0BSD",
-		),
-		"cc" => (licenses::cc::get(), "CC-BY-4.0"),
-		"cc_sa" => (licenses::cc_sa::get(), "CC-BY-SA-4.0"),
-		"cc_zero" => (licenses::cc_zero::get(), "CC0-1.0"),
-		"cecill" => (licenses::cecill::get(), "CECILL-2.1"),
-		"ecl" => (licenses::ecl::get(), "ECL-2.0"),
-		"eclipse_one" => (licenses::eclipse_one::get(), "EPL-1.0"),
-		"eclipse_two" => (licenses::eclipse_two::get(), "EPL-2.0"),
-		"eu_one" => (licenses::eu_one::get(), "EUPL-1.1"),
-		"eu_two" => (licenses::eu_two::get(), "EUPL-1.2"),
-		"fdl" => (licenses::fdl::get(), "FDL-1.3"),
-		"gpl_three" => (licenses::gpl_three::get(), "GPL-3.0-only"),
-		"gpl_two" => (licenses::gpl_two::get(), "GPL-2.0-only"),
-		"isc" => (
-			licenses::isc::get(year, drain_names(emails, names, project, url)),
-			"ISC",
-		),
-		"latex" => (licenses::latex::get(), "LPPL-1.3c"),
-		"lgpl_three" => (licenses::lgpl_three::get(), "LGPL-3.0-only"),
-		"lgpl_two" => (licenses::lgpl_two::get(), "LGPL-2.1-only"),
-		"micpl" => (licenses::micpl::get(), "MICROSOFT-PL"),
-		"mit" => (
-			licenses::mit::get(year, drain_names(emails, names, project, url)),
-			"MIT",
-		),
-		"mit_na" => (
-			licenses::mit_na::get(year, drain_names(emails, names, project, url)),
-			"MIT-0",
-		),
-		"mozpl" => (licenses::mozpl::get(), "MPL-2.0"),
-		"mrl" => (licenses::mrl::get(), "MIROSL"),
-		"mulpl" => (licenses::mulpl::get(), "MULPL-1.0"),
-		"ncsa" => {
-			let mut pkg = get_info(emails, names, project, url, (true, true, false));
-
-			(
-				licenses::ncsa::get(
-					year,
-					pkg.authors.drain(0..).map(|author| author.name).collect(),
-					pkg.name.unwrap(),
-					pkg.url.unwrap(),
-				),
-				"NCSA",
-			)
-		}
-		"odl" => (licenses::odl::get(), "ODbL-1.0"),
-		"ofl" => {
-			let mut pkg = get_info(emails, names, project, url, (false, false, true));
+	Ok((render_text(root, license, values, true)?, license.spdx))
+}
 
-			let (mut pkg_names, mut pkg_emails) = (Vec::new(), Vec::new());
+pub(crate) fn render_text(
+	root: &Project,
+	license: &License,
+	values: Values,
+	appendix: bool,
+) -> Result<String, LicerError> {
+	let text =
+		license
+			.translation(values.lang.as_deref())
+			.ok_or_else(|| LicerError::UnknownLang {
+				lang: values.lang.to_owned().unwrap_or_default(),
+				langs: license.langs().join(", "),
+			})?;
+	let (text, fields) = if appendix {
+		(text, license.fields.to_vec())
+	} else {
+		let text = licenses::strip_appendix(text);
+		let used = licenses::infer_fields(text);
 
-			for author in pkg.authors.drain(0..) {
-				pkg_names.push(author.name);
+		(
+			text,
+			license
+				.fields
+				.iter()
+				.copied()
+				.filter(|field| used.contains(field))
+				.collect(),
+		)
+	};
 
-				if let Some(email) = author.email {
-					pkg_emails.push(email);
-				}
-			}
+	let values = resolve_values(root, &fields, values)?;
 
-			(licenses::ofl::get(year, pkg_names, pkg_emails), "OFL-1.1")
+	if let Some(region) = licenses::unresolved_region(license) {
+		LicerError::UnresolvedPlaceholder {
+			license: license.id.to_owned(),
+			key: region.to_owned(),
 		}
-		"osl" => (licenses::osl::get(), "OSL-3.0"),
-		"postgres" => (licenses::postgres::get(year, names), "PostgreSQL"),
-		"unl" => (licenses::unl::get(), "Unlicense"),
-		"upl" => (licenses::upl::get(year, names), "UPL-1.0"),
-		"vim" => (licenses::vim::get(), "Vim"),
-		"zlib" => (licenses::zlib::get(year, names), "Zlib"),
-		l => CliError::UnknownLicense {
-			license: l.to_owned(),
-		}
-		.throw(),
+		.warn();
 	}
+
+	license
+		.render_text(&values.expand_lines(text), |field| {
+			values.get(field).unwrap_or_default()
+		})
+		.map_err(|key| LicerError::UnresolvedPlaceholder {
+			license: license.id.to_owned(),
+			key,
+		})
 }
 
-pub fn write_pkg(license_type: &str) {
-	let success = |pkg_type: &str| {
-		println!(
-			"{} Wrote license type '{}' to '{}'",
-			"SUCCESS".green().bold(),
-			license_type,
-			pkg_type
-		);
+pub fn render_notice(
+	root: &Project,
+	license: &License,
+	values: Values,
+) -> Result<Option<String>, LicerError> {
+	let notice = match license.gnu() {
+		Some(gnu) => gnu.notice,
+		None => return Ok(None),
 	};
+	let fields = [Field::Year, Field::Names, Field::Project];
+	let values = resolve_values(root, &fields, values)?;
+
+	licenses::render(&values.expand_lines(notice), |key| {
+		fields
+			.iter()
+			.find(|field| field.key() == key)
+			.map(|field| values.get(*field).unwrap_or_default())
+	})
+	.map(Some)
+	.map_err(|key| LicerError::UnresolvedPlaceholder {
+		license: license.id.to_owned(),
+		key,
+	})
+}
+
+fn update_pkg(
+	root: &Project,
+	path: &Path,
+	before: &str,
+	after: &str,
+	license_type: &str,
+	dry_run: bool,
+) -> Result<ManifestUpdate, LicerError> {
+	let name = path.to_string_lossy();
+	let diff = TextDiff::from_lines(before, after)
+		.unified_diff()
+		.header(&format!("a/{}", name), &format!("b/{}", name))
+		.to_string();
 
-	match read_to_string("package.json") {
+	if !dry_run {
+		root.fs()
+			.write(path, after)
+			.map_err(|_| LicerError::FailedWrite {
+				path: path.to_path_buf(),
+			})?;
+	}
+
+	Ok(ManifestUpdate {
+		path: path.to_path_buf(),
+		license: license_type.to_owned(),
+		diff,
+	})
+}
+
+fn write_node(
+	root: &Project,
+	path: &Path,
+	license_type: &str,
+	dry_run: bool,
+) -> Result<Option<ManifestUpdate>, LicerError> {
+	let kind = path.to_string_lossy().into_owned();
+
+	match root.fs().read(path) {
 		Err(err) => {
 			if err.kind() != ErrorKind::NotFound {
-				CliError::FailedRead {
-					kind: "package.json".to_owned(),
-				}
-				.warn();
+				LicerError::FailedRead { kind }.warn();
 			}
 		}
 		Ok(string) => match parse(&string) {
-			Err(_) => CliError::FailedParse {
-				kind: "package.json".to_owned(),
-			}
-			.warn(),
+			Err(_) => LicerError::FailedParse { kind }.warn(),
 			Ok(mut json) => {
-				drop(string);
+				let license_type = if is_proprietary(license_type) {
+					json["private"] = true.into();
+					"UNLICENSED"
+				} else {
+					license_type
+				};
 
 				json["license"] = license_type.into();
 
-				write("package.json", json.pretty(4).replace("    ", "	")).unwrap_or_else(|_| {
-					CliError::FailedWrite {
-						path: PathBuf::from("package.json"),
-					}
-					.throw()
-				});
-
-				return success("package.json");
+				return update_pkg(
+					root,
+					path,
+					&string,
+					&json.pretty(4).replace("    ", "	"),
+					license_type,
+					dry_run,
+				)
+				.map(Some);
 			}
 		},
 	}
 
-	match read_to_string("Cargo.toml") {
+	Ok(None)
+}
+
+pub fn write_pkg(
+	root: &Project,
+	license_type: &str,
+	dry_run: bool,
+) -> Result<Vec<ManifestUpdate>, LicerError> {
+	if let Some(update) = write_node(root, &root.path("package.json"), license_type, dry_run)? {
+		return Ok(vec![update]);
+	}
+
+	match root.read("Cargo.toml") {
 		Err(err) => {
 			if err.kind() != ErrorKind::NotFound {
-				CliError::FailedRead {
+				LicerError::FailedRead {
 					kind: "Cargo.toml".to_owned(),
 				}
 				.warn();
 			}
 		}
 		Ok(string) => match string.parse::<Document>() {
-			Err(_) => CliError::FailedParse {
+			Err(_) => LicerError::FailedParse {
 				kind: "Cargo.toml".to_owned(),
 			}
 			.warn(),
 			Ok(mut toml) => {
-				drop(string);
+				if toml.contains_key("workspace") {
+					return write_cargo_workspace(root, &string, toml, license_type, dry_run);
+				}
 
 				toml["package"]["license"] = value(license_type);
 
-				write("Cargo.toml", toml.to_string()).unwrap_or_else(|_| {
-					CliError::FailedWrite {
-						path: PathBuf::from("Cargo.toml"),
-					}
-					.throw()
-				});
+				if is_proprietary(license_type) {
+					toml["package"]["publish"] = value(false);
+				}
 
-				return success("Cargo.toml");
+				return update_pkg(
+					root,
+					&root.path("Cargo.toml"),
+					&string,
+					&toml.to_string(),
+					license_type,
+					dry_run,
+				)
+				.map(|update| vec![update]);
 			}
 		},
 	}
 
-	match read_to_string("pyproject.toml") {
+	match root.read("pyproject.toml") {
 		Err(err) => {
 			if err.kind() != ErrorKind::NotFound {
-				CliError::FailedRead {
-					kind: "Cargo.toml".to_owned(),
+				LicerError::FailedRead {
+					kind: "pyproject.toml".to_owned(),
 				}
 				.warn();
 			}
 		}
 		Ok(string) => match string.parse::<Document>() {
-			Err(_) => CliError::FailedParse {
-				kind: "Cargo.toml".to_owned(),
+			Err(_) => LicerError::FailedParse {
+				kind: "pyproject.toml".to_owned(),
 			}
 			.warn(),
 			Ok(mut toml) => {
-				drop(string);
+				let project = match toml
+					.entry("project")
+					.or_insert(Item::Table(Table::new()))
+					.as_table_like_mut()
+				{
+					Some(project) => project,
+					None => {
+						return Err(LicerError::FailedParse {
+							kind: "pyproject.toml".to_owned(),
+						})
+					}
+				};
+
+				project.insert("license", value(license_type));
 
-				toml["license"] = value(license_type);
+				if is_proprietary(license_type) {
+					let classifiers = project.entry("classifiers").or_insert(value(Array::new()));
 
-				write("pyproject.toml", toml.to_string()).unwrap_or_else(|_| {
-					CliError::FailedWrite {
-						path: PathBuf::from("pyproject.toml"),
+					if let Some(classifiers) = classifiers.as_array_mut() {
+						if !classifiers
+							.iter()
+							.any(|classifier| classifier.as_str() == Some(DO_NOT_UPLOAD))
+						{
+							classifiers.push(DO_NOT_UPLOAD);
+						}
 					}
-					.throw()
-				});
+				}
 
-				success("Cargo.toml")
+				return update_pkg(
+					root,
+					&root.path("pyproject.toml"),
+					&string,
+					&toml.to_string(),
+					license_type,
+					dry_run,
+				)
+				.map(|update| vec![update]);
 			}
 		},
 	}
+
+	Ok(Vec::new())
+}
+
+const DO_NOT_UPLOAD: &str = "Private :: Do Not Upload";
+
+fn is_proprietary(license_type: &str) -> bool {
+	license_type == licenses::proprietary::LICENSE.spdx
+}
+
+fn set_member_license(package: &mut Item, license_type: &str) {
+	if is_proprietary(license_type) {
+		package["publish"] = value(false);
+	}
+
+	if package
+		.get("license")
+		.is_some_and(|license| license.is_str())
+	{
+		package["license"] = value(license_type);
+	} else {
+		let mut inherit = InlineTable::new();
+
+		inherit.insert("workspace", true.into());
+		inherit.set_dotted(true);
+
+		package["license"] = value(inherit);
+	}
+}
+
+fn write_cargo_workspace(
+	root: &Project,
+	string: &str,
+	mut toml: Document,
+	license_type: &str,
+	dry_run: bool,
+) -> Result<Vec<ManifestUpdate>, LicerError> {
+	let members = cargo_members(root, &toml);
+
+	match toml["workspace"].as_table_mut() {
+		Some(workspace) => {
+			workspace
+				.entry("package")
+				.or_insert(Item::Table(Table::new()))["license"] = value(license_type)
+		}
+		None => {
+			return Err(LicerError::FailedParse {
+				kind: "Cargo.toml".to_owned(),
+			})
+		}
+	}
+
+	if toml.contains_key("package") {
+		set_member_license(&mut toml["package"], license_type);
+	}
+
+	let mut updates = vec![update_pkg(
+		root,
+		&root.path("Cargo.toml"),
+		string,
+		&toml.to_string(),
+		license_type,
+		dry_run,
+	)?];
+
+	for member in members {
+		let path = root.path(member.join("Cargo.toml"));
+		let kind = path.to_string_lossy().into_owned();
+
+		match root.fs().read(&path) {
+			Err(_) => LicerError::FailedRead { kind }.warn(),
+			Ok(string) => match string.parse::<Document>() {
+				Err(_) => LicerError::FailedParse { kind }.warn(),
+				Ok(mut toml) => {
+					if !toml.contains_key("package") {
+						continue;
+					}
+
+					set_member_license(&mut toml["package"], license_type);
+
+					updates.push(update_pkg(
+						root,
+						&path,
+						&string,
+						&toml.to_string(),
+						license_type,
+						dry_run,
+					)?);
+				}
+			},
+		}
+	}
+
+	Ok(updates)
+}
+
+pub enum Existing {
+	Missing,
+	Identical,
+	Different { license: Option<&'static str> },
+}
+
+pub fn check_existing(root: &Project, path: &Path, text: &str) -> Existing {
+	match root.fs().read(path) {
+		Err(_) => Existing::Missing,
+		Ok(existing) if existing == text => Existing::Identical,
+		Ok(existing) => Existing::Different {
+			license: detect_license(&existing),
+		},
+	}
+}
+
+pub fn backup_file(root: &Project, path: &Path) -> Result<Option<PathBuf>, LicerError> {
+	let fs = root.fs();
+	let name = match path.file_name().and_then(|name| name.to_str()) {
+		Some(name) if fs.is_file(path) => name,
+		_ => return Ok(None),
+	};
+
+	let backup = (0..)
+		.map(|i| match i {
+			0 => path.with_file_name(format!("{}.bak", name)),
+			i => path.with_file_name(format!("{}.bak.{}", name, i)),
+		})
+		.find(|backup| !fs.is_file(backup) && !fs.is_dir(backup));
+
+	match backup {
+		Some(backup) => match fs.read(path).and_then(|text| fs.write(&backup, &text)) {
+			Ok(_) => Ok(Some(backup)),
+			Err(_) => Err(LicerError::FailedWrite { path: backup }),
+		},
+		None => Ok(None),
+	}
+}
+
+pub fn write_members(
+	root: &Project,
+	text: &str,
+	file: &Path,
+	license_type: &str,
+	dry_run: bool,
+	confirm: impl Fn(&Path) -> bool,
+	backup: bool,
+) -> Result<(Vec<PathBuf>, Vec<ManifestUpdate>), LicerError> {
+	let (mut written, mut updates) = (Vec::new(), Vec::new());
+	let members = find_members(root);
+
+	if members.is_empty() {
+		LicerError::MissingMembers.warn();
+	}
+
+	for member in members {
+		let path = root.path(member.join(file));
+
+		if !dry_run {
+			let existing = check_existing(root, &path, text);
+
+			if matches!(existing, Existing::Different { .. }) && !confirm(&path) {
+				LicerError::ExistingLicense { path }.warn();
+				continue;
+			}
+
+			if backup {
+				backup_file(root, &path)?;
+			}
+
+			if root.fs().write(&path, text).is_err() {
+				LicerError::FailedWrite { path }.warn();
+				continue;
+			}
+
+			written.push(path);
+		}
+
+		updates.extend(write_node(
+			root,
+			&root.path(member.join("package.json")),
+			license_type,
+			dry_run,
+		)?);
+	}
+
+	Ok((written, updates))
 }
@@ -1,7 +1,26 @@
 // Academic Free License - https://choosealicense.com/licenses/afl-3.0
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "afl",
+	name: "Academic Free License",
+	spdx: "AFL-3.0",
+	fields: &[],
+	permissions: &[
+		CommercialUse,
+		Modifications,
+		Distribution,
+		PatentUse,
+		PrivateUse,
+	],
+	conditions: &[IncludeCopyright, DocumentChanges],
+	limitations: &[TrademarkUse, Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 Academic Free License ("AFL") v. 3.0
 
 This Academic Free License (the "License") applies to any original work of
@@ -173,6 +192,4 @@ must replace the notice specified in the first paragraph above with the notice
 that is not confusingly similar to the notice in this License; and (iii) You
 may not claim that your original works are open source software unless your
 Modified License has been approved by Open Source Initiative (OSI) and You
-comply with its license review and certification process."#
-		.to_owned()
-}
+comply with its license review and certification process."#;
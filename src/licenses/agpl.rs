@@ -1,7 +1,32 @@
 // GNU Affero General Public License v3.0 - https://choosealicense.com/licenses/agpl-3.0
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "agpl",
+	name: "GNU Affero General Public License v3.0",
+	spdx: "AGPL-3.0-only",
+	fields: &[],
+	permissions: &[
+		CommercialUse,
+		Modifications,
+		Distribution,
+		PatentUse,
+		PrivateUse,
+	],
+	conditions: &[
+		IncludeCopyright,
+		DocumentChanges,
+		DiscloseSource,
+		NetworkUseDisclose,
+		SameLicense,
+	],
+	limitations: &[Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 GNU AFFERO GENERAL PUBLIC LICENSE
 Version 3, 19 November 2007
 
@@ -618,35 +643,20 @@ above cannot be given local legal effect according to their terms,
 reviewing courts shall apply local law that most closely approximates
 an absolute waiver of all civil liability in connection with the
 Program, unless a warranty or assumption of liability accompanies a
-copy of the Program in return for a fee.
-
-ADDENDUM: How to use this License for your documents
-
-To use this License in a document you have written, include a copy of
-the License in the document and put the following copyright and
-license notices just after the title page:
-
-    Copyright (c)  YEAR  YOUR NAME.
-    Permission is granted to copy, distribute and/or modify this document
-    under the terms of the GNU Free Documentation License, Version 1.3
-    or any later version published by the Free Software Foundation;
-    with no Invariant Sections, no Front-Cover Texts, and no Back-Cover Texts.
-    A copy of the license is included in the section entitled "GNU
-    Free Documentation License".
-
-If you have Invariant Sections, Front-Cover Texts and Back-Cover Texts,
-replace the "with...Texts." line with this:
-
-    with the Invariant Sections being LIST THEIR TITLES, with the
-    Front-Cover Texts being LIST, and with the Back-Cover Texts being LIST.
-
-If you have Invariant Sections without Cover Texts, or some other
-combination of the three, merge those two alternatives to suit the
-situation.
-
-If your document contains nontrivial examples of program code, we
-recommend releasing these examples in parallel under your choice of
-free software license, such as the GNU General Public License,
-to permit their use in free software."#
-		.to_owned()
-}
+copy of the Program in return for a fee."#;
+
+pub const NOTICE: &str = r#"
+{{project}}
+Copyright (C) {{year}} {{holders}}
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU Affero General Public License as published
+by the Free Software Foundation, version 3 of the License.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+GNU Affero General Public License for more details.
+
+You should have received a copy of the GNU Affero General Public License
+along with this program. If not, see <https://www.gnu.org/licenses/>."#;
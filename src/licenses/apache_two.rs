@@ -1,7 +1,26 @@
 // Apache License 2.0 - https://choosealicense.com/licenses/apache-2.0
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "apache_two",
+	name: "Apache License 2.0",
+	spdx: "Apache-2.0",
+	fields: &[],
+	permissions: &[
+		CommercialUse,
+		Modifications,
+		Distribution,
+		PatentUse,
+		PrivateUse,
+	],
+	conditions: &[IncludeCopyright, DocumentChanges],
+	limitations: &[TrademarkUse, Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 Apache License
 Version 2.0, January 2004
 http://www.apache.org/licenses/
@@ -202,6 +221,4 @@ Unless required by applicable law or agreed to in writing, software
 distributed under the License is distributed on an "AS IS" BASIS,
 WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 See the License for the specific language governing permissions and
-limitations under the License."#
-		.to_owned()
-}
+limitations under the License."#;
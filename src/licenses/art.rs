@@ -1,7 +1,26 @@
 // Artistic License 2.0 - https://choosealicense.com/licenses/artistic-2.0
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "art",
+	name: "Artistic License 2.0",
+	spdx: "Artistic-2.0",
+	fields: &[],
+	permissions: &[
+		CommercialUse,
+		Modifications,
+		Distribution,
+		PatentUse,
+		PrivateUse,
+	],
+	conditions: &[IncludeCopyright, DocumentChanges],
+	limitations: &[TrademarkUse, Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 The Artistic License 2.0
 
 Copyright (c) 2000-2006, The Perl Foundation.
@@ -188,6 +207,4 @@ NON-INFRINGEMENT ARE DISCLAIMED TO THE EXTENT PERMITTED BY YOUR LOCAL
 LAW. UNLESS REQUIRED BY LAW, NO COPYRIGHT HOLDER OR CONTRIBUTOR WILL
 BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, OR CONSEQUENTIAL
 DAMAGES ARISING IN ANY WAY OUT OF THE USE OF THE PACKAGE, EVEN IF
-ADVISED OF THE POSSIBILITY OF SUCH DAMAGE."#
-		.to_owned()
-}
+ADVISED OF THE POSSIBILITY OF SUCH DAMAGE."#;
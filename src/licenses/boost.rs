@@ -1,7 +1,20 @@
 // Boost Software License 1.0 - https://choosealicense.com/licenses/bsl-1.0
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "boost",
+	name: "Boost Software License 1.0",
+	spdx: "BSL-1.0",
+	fields: &[],
+	permissions: &[CommercialUse, Modifications, Distribution, PrivateUse],
+	conditions: &[IncludeCopyrightSource],
+	limitations: &[Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 Boost Software License - Version 1.0 - August 17th, 2003
 
 Permission is hereby granted, free of charge, to any person or organization
@@ -24,6 +37,4 @@ FITNESS FOR A PARTICULAR PURPOSE, TITLE AND NON-INFRINGEMENT. IN NO EVENT
 SHALL THE COPYRIGHT HOLDERS OR ANYONE DISTRIBUTING THE SOFTWARE BE LIABLE
 FOR ANY DAMAGES OR OTHER LIABILITY, WHETHER IN CONTRACT, TORT OR OTHERWISE,
 ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
-DEALINGS IN THE SOFTWARE."#
-		.to_owned()
-}
+DEALINGS IN THE SOFTWARE."#;
@@ -1,11 +1,23 @@
 // BSD 3-Clause Clear License - https://choosealicense.com/licenses/bsd-3-clause-clear
 
-pub fn get(year: String, names: Vec<String>) -> String {
-	format!(
-		r#"
+use super::{Field::*, License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "bsd_clear",
+	name: "BSD 3-Clause Clear License",
+	spdx: "BSD-3-Clause-Clear",
+	fields: &[Year, Names],
+	permissions: &[CommercialUse, Modifications, Distribution, PrivateUse],
+	conditions: &[IncludeCopyright],
+	limitations: &[Liability, PatentUse, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 The Clear BSD License
 
-Copyright (c) {} {}
+Copyright (c) {{year}} {{holders}}
 All rights reserved.
 
 Redistribution and use in source and binary forms, with or without
@@ -34,8 +46,4 @@ PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR
 BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER
 IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE)
 ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE
-POSSIBILITY OF SUCH DAMAGE."#,
-		year,
-		names.join(", ")
-	)
-}
+POSSIBILITY OF SUCH DAMAGE."#;
@@ -1,11 +1,23 @@
 // BSD 4-Clause “Original” or “Old” License - https://choosealicense.com/licenses/bsd-4-clause
 
-pub fn get(year: String, names: Vec<String>) -> String {
-	format!(
-		r#"
+use super::{Field::*, License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "bsd_four",
+	name: "BSD 4-Clause “Original” or “Old” License",
+	spdx: "BSD-4-Clause",
+	fields: &[Year, Names, Project],
+	permissions: &[CommercialUse, Modifications, Distribution, PrivateUse],
+	conditions: &[IncludeCopyright],
+	limitations: &[Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 BSD 4-Clause License
 
-Copyright (c) {}, {}
+Copyright (c) {{year}}, {{holders}}
 All rights reserved.
 
 Redistribution and use in source and binary forms, with or without
@@ -20,7 +32,7 @@ and/or other materials provided with the distribution.
 
 3. All advertising materials mentioning features or use of this software must
 display the following acknowledgement:
-This product includes software developed by [project].
+This product includes software developed by {{project}}.
 
 4. Neither the name of the copyright holder nor the names of its
 contributors may be used to endorse or promote products derived from
@@ -35,8 +47,4 @@ PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
 OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
 WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR
 OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF
-ADVISED OF THE POSSIBILITY OF SUCH DAMAGE."#,
-		year,
-		names.join(", ")
-	)
-}
+ADVISED OF THE POSSIBILITY OF SUCH DAMAGE."#;
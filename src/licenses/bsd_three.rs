@@ -1,11 +1,23 @@
 // BSD 3-Clause “New” or “Revised” License - https://choosealicense.com/licenses/bsd-3-clause
 
-pub fn get(year: String, names: Vec<String>) -> String {
-	format!(
-		r#"
+use super::{Field::*, License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "bsd_three",
+	name: "BSD 3-Clause “New” or “Revised” License",
+	spdx: "BSD-3-Clause",
+	fields: &[Year, Names],
+	permissions: &[CommercialUse, Modifications, Distribution, PrivateUse],
+	conditions: &[IncludeCopyright],
+	limitations: &[Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 BSD 3-Clause License
 
-Copyright (c) {}, {}
+Copyright (c) {{year}}, {{holders}}
 
 Redistribution and use in source and binary forms, with or without
 modification, are permitted provided that the following conditions are met:
@@ -30,8 +42,4 @@ DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
 SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
 CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
 OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE,
-OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE."#,
-		year,
-		names.join(", ")
-	)
-}
+OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE."#;
@@ -1,11 +1,23 @@
 // BSD 2-Clause “Simplified” License - https://choosealicense.com/licenses/bsd-2-clause
 
-pub fn get(year: String, names: Vec<String>) -> String {
-	format!(
-		r#"
+use super::{Field::*, License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "bsd_two",
+	name: "BSD 2-Clause “Simplified” License",
+	spdx: "BSD-2-Clause",
+	fields: &[Year, Names],
+	permissions: &[CommercialUse, Modifications, Distribution, PrivateUse],
+	conditions: &[IncludeCopyright],
+	limitations: &[Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 BSD 2-Clause License
 
-Copyright (c) {}, {}
+Copyright (c) {{year}}, {{holders}}
 
 Redistribution and use in source and binary forms, with or without
 modification, are permitted provided that the following conditions are met:
@@ -26,8 +38,4 @@ DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
 SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
 CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
 OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
-OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE."#,
-		year,
-		names.join(", ")
-	)
-}
+OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE."#;
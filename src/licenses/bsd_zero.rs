@@ -1,11 +1,23 @@
 // BSD Zero Clause License - https://choosealicense.com/licenses/0bsd
 
-pub fn get(year: String, names: Vec<String>) -> String {
-	format!(
-		r#"
+use super::{Field::*, License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "bsd_zero",
+	name: "BSD Zero Clause License",
+	spdx: "0BSD",
+	fields: &[Year, Names],
+	permissions: &[CommercialUse, Modifications, Distribution, PrivateUse],
+	conditions: &[],
+	limitations: &[Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 BSD Zero Clause License
 
-Copyright (c) {} {}
+Copyright (c) {{year}} {{holders}}
 
 Permission to use, copy, modify, and/or distribute this software for any
 purpose with or without fee is hereby granted.
@@ -16,8 +28,4 @@ AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY SPECIAL, DIRECT,
 INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES WHATSOEVER RESULTING FROM
 LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF CONTRACT, NEGLIGENCE OR
 OTHER TORTIOUS ACTION, ARISING OUT OF OR IN CONNECTION WITH THE USE OR
-PERFORMANCE OF THIS SOFTWARE."#,
-		year,
-		names.join(", ")
-	)
-}
+PERFORMANCE OF THIS SOFTWARE."#;
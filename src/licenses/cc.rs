@@ -1,7 +1,20 @@
 // Creative Commons Attribution 4.0 International - https://choosealicense.com/licenses/cc-by-4.0
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "cc",
+	name: "Creative Commons Attribution 4.0 International",
+	spdx: "CC-BY-4.0",
+	fields: &[],
+	permissions: &[CommercialUse, Modifications, Distribution, PrivateUse],
+	conditions: &[IncludeCopyright, DocumentChanges],
+	limitations: &[Liability, TrademarkUse, PatentUse, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 Attribution 4.0 International
 
 =======================================================================
@@ -396,6 +409,4 @@ understandings, or agreements concerning use of licensed material. For
 the avoidance of doubt, this paragraph does not form part of the public
 licenses.
 
-Creative Commons may be contacted at creativecommons.org."#
-		.to_owned()
-}
+Creative Commons may be contacted at creativecommons.org."#;
@@ -1,7 +1,20 @@
 // Creative Commons Attribution Share Alike 4.0 International - https://choosealicense.com/licenses/cc-by-sa-4.0
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "cc_sa",
+	name: "Creative Commons Attribution Share Alike 4.0 International",
+	spdx: "CC-BY-SA-4.0",
+	fields: &[],
+	permissions: &[CommercialUse, Modifications, Distribution, PrivateUse],
+	conditions: &[IncludeCopyright, DocumentChanges, SameLicense],
+	limitations: &[Liability, TrademarkUse, PatentUse, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 Attribution-ShareAlike 4.0 International
 
 =======================================================================
@@ -428,6 +441,4 @@ understandings, or agreements concerning use of licensed material. For
 the avoidance of doubt, this paragraph does not form part of the public
 licenses.
 
-Creative Commons may be contacted at creativecommons.org."#
-		.to_owned()
-}
+Creative Commons may be contacted at creativecommons.org."#;
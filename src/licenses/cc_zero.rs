@@ -1,7 +1,20 @@
 // Creative Commons Zero v1.0 Universal - https://choosealicense.com/licenses/cc0-1.0
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "cc_zero",
+	name: "Creative Commons Zero v1.0 Universal",
+	spdx: "CC0-1.0",
+	fields: &[],
+	permissions: &[CommercialUse, Modifications, Distribution, PrivateUse],
+	conditions: &[],
+	limitations: &[Liability, TrademarkUse, PatentUse, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 Creative Commons Legal Code
 
 CC0 1.0 Universal
@@ -122,6 +135,4 @@ express Statement of Purpose.
        Work.
     d. Affirmer understands and acknowledges that Creative Commons is not a
        party to this document and has no duty or obligation with respect to
-       this CC0 or use of the Work."#
-		.to_owned()
-}
+       this CC0 or use of the Work."#;
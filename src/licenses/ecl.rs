@@ -1,7 +1,26 @@
 // Educational Community License v2.0 - https://choosealicense.com/licenses/ecl-2.0
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "ecl",
+	name: "Educational Community License v2.0",
+	spdx: "ECL-2.0",
+	fields: &[],
+	permissions: &[
+		CommercialUse,
+		Modifications,
+		Distribution,
+		PatentUse,
+		PrivateUse,
+	],
+	conditions: &[IncludeCopyright, DocumentChanges],
+	limitations: &[TrademarkUse, Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 Educational Community License
 
 Version 2.0, April 2007
@@ -204,6 +223,4 @@ http://opensource.org/licenses/ECL-2.0
 distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
 WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
 License for the specific language governing permissions and limitations under
-the License."#
-		.to_owned()
-}
+the License."#;
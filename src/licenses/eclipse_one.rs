@@ -1,7 +1,26 @@
 // Eclipse Public License 1.0 - https://choosealicense.com/licenses/epl-1.0
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "eclipse_one",
+	name: "Eclipse Public License 1.0",
+	spdx: "EPL-1.0",
+	fields: &[],
+	permissions: &[
+		CommercialUse,
+		Modifications,
+		Distribution,
+		PatentUse,
+		PrivateUse,
+	],
+	conditions: &[DiscloseSource, IncludeCopyright, SameLicense],
+	limitations: &[Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 Eclipse Public License - v 1.0
 
 THE ACCOMPANYING PROGRAM IS PROVIDED UNDER THE TERMS OF THIS ECLIPSE PUBLIC
@@ -205,6 +224,4 @@ This Agreement is governed by the laws of the State of New York and the
 intellectual property laws of the United States of America. No party to this
 Agreement will bring a legal action under this Agreement more than one year
 after the cause of action arose. Each party waives its rights to a jury trial
-in any resulting litigation."#
-		.to_owned()
-}
+in any resulting litigation."#;
@@ -1,7 +1,26 @@
 // Eclipse Public License 2.0 - https://choosealicense.com/licenses/epl-2.0
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "eclipse_two",
+	name: "Eclipse Public License 2.0",
+	spdx: "EPL-2.0",
+	fields: &[],
+	permissions: &[
+		CommercialUse,
+		Modifications,
+		Distribution,
+		PatentUse,
+		PrivateUse,
+	],
+	conditions: &[DiscloseSource, IncludeCopyright, SameLicense],
+	limitations: &[Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 Eclipse Public License - v 2.0
 
     THE ACCOMPANYING PROGRAM IS PROVIDED UNDER THE TERMS OF THIS ECLIPSE
@@ -278,6 +297,4 @@ version(s), and exceptions or additional permissions here}."
   file in a relevant directory) where a recipient would be likely to
   look for such a notice.
 
-  You may add additional accurate notices of copyright ownership."#
-		.to_owned()
-}
+  You may add additional accurate notices of copyright ownership."#;
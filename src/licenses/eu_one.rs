@@ -1,7 +1,32 @@
 // European Union Public License 1.1 - https://choosealicense.com/licenses/eupl-1.1
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "eu_one",
+	name: "European Union Public License 1.1",
+	spdx: "EUPL-1.1",
+	fields: &[],
+	permissions: &[
+		CommercialUse,
+		Modifications,
+		Distribution,
+		PatentUse,
+		PrivateUse,
+	],
+	conditions: &[
+		NetworkUseDisclose,
+		DiscloseSource,
+		IncludeCopyright,
+		DocumentChanges,
+		SameLicense,
+	],
+	limitations: &[TrademarkUse, Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 European Union Public Licence
 V. 1.1
 
@@ -312,6 +337,4 @@ Appendix
 - Open Software License (OSL) v. 2.1, v. 3.0
 - Common Public License v. 1.0
 - Eclipse Public License v. 1.0
-- Cecill v. 2.0"#
-		.to_owned()
-}
+- Cecill v. 2.0"#;
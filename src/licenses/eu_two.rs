@@ -1,7 +1,32 @@
 // European Union Public License 1.2 - https://choosealicense.com/licenses/eupl-1.2
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "eu_two",
+	name: "European Union Public License 1.2",
+	spdx: "EUPL-1.2",
+	fields: &[],
+	permissions: &[
+		CommercialUse,
+		Modifications,
+		Distribution,
+		PatentUse,
+		PrivateUse,
+	],
+	conditions: &[
+		NetworkUseDisclose,
+		DiscloseSource,
+		IncludeCopyright,
+		DocumentChanges,
+		SameLicense,
+	],
+	limitations: &[TrademarkUse, Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 EUROPEAN UNION PUBLIC LICENCE v. 1.2
 EUPL © the European Union 2007, 2016
 
@@ -292,6 +317,4 @@ provide the rights granted in Article 2 of this Licence and protect the
 covered Source Code from exclusive appropriation.
 
 All other changes or additions to this Appendix require the production of a
-new EUPL version."#
-		.to_owned()
-}
+new EUPL version."#;
@@ -1,7 +1,25 @@
 // GNU Free Documentation License v1.3 - https://choosealicense.com/licenses/gfdl-1.3
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "fdl",
+	name: "GNU Free Documentation License v1.3",
+	spdx: "GFDL-1.3-only",
+	fields: &[],
+	permissions: &[CommercialUse, Modifications, Distribution, PrivateUse],
+	conditions: &[
+		IncludeCopyright,
+		DocumentChanges,
+		DiscloseSource,
+		SameLicense,
+	],
+	limitations: &[Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 GNU Free Documentation License
 Version 1.3, 3 November 2008
 
@@ -451,6 +469,4 @@ situation.
 If your document contains nontrivial examples of program code, we
 recommend releasing these examples in parallel under your choice of
 free software license, such as the GNU General Public License,
-to permit their use in free software."#
-		.to_owned()
-}
+to permit their use in free software."#;
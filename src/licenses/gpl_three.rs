@@ -1,7 +1,31 @@
 // GNU General Public License v3.0 - https://choosealicense.com/licenses/gpl-3.0
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "gpl_three",
+	name: "GNU General Public License v3.0",
+	spdx: "GPL-3.0-only",
+	fields: &[],
+	permissions: &[
+		CommercialUse,
+		Modifications,
+		Distribution,
+		PatentUse,
+		PrivateUse,
+	],
+	conditions: &[
+		IncludeCopyright,
+		DocumentChanges,
+		DiscloseSource,
+		SameLicense,
+	],
+	limitations: &[Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 GNU GENERAL PUBLIC LICENSE
 Version 3, 29 June 2007
 
@@ -620,6 +644,20 @@ above cannot be given local legal effect according to their terms,
 reviewing courts shall apply local law that most closely approximates
 an absolute waiver of all civil liability in connection with the
 Program, unless a warranty or assumption of liability accompanies a
-copy of the Program in return for a fee."#
-		.to_owned()
-}
+copy of the Program in return for a fee."#;
+
+pub const NOTICE: &str = r#"
+{{project}}
+Copyright (C) {{year}} {{holders}}
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, version 3 of the License.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program. If not, see <https://www.gnu.org/licenses/>."#;
@@ -1,7 +1,25 @@
 // GNU General Public License v2.0 - https://choosealicense.com/licenses/gpl-2.0
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "gpl_two",
+	name: "GNU General Public License v2.0",
+	spdx: "GPL-2.0-only",
+	fields: &[],
+	permissions: &[CommercialUse, Modifications, Distribution, PrivateUse],
+	conditions: &[
+		IncludeCopyright,
+		DocumentChanges,
+		DiscloseSource,
+		SameLicense,
+	],
+	limitations: &[Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 GNU GENERAL PUBLIC LICENSE
 Version 2, June 1991
 
@@ -340,6 +358,21 @@ This General Public License does not permit incorporating your program into
 proprietary programs. If your program is a subroutine library, you may
 consider it more useful to permit linking proprietary applications with the
 library. If this is what you want to do, use the GNU Lesser General
-Public License instead of this License."#
-		.to_owned()
-}
+Public License instead of this License."#;
+
+pub const NOTICE: &str = r#"
+{{project}}
+Copyright (C) {{year}} {{holders}}
+
+This program is free software; you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, version 2 of the License.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along
+with this program; if not, write to the Free Software Foundation, Inc.,
+51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA."#;
@@ -1,7 +1,20 @@
 // LaTeX Project Public License v1.3c - https://choosealicense.com/licenses/lppl-1.3c
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "latex",
+	name: "LaTeX Project Public License v1.3c",
+	spdx: "LPPL-1.3c",
+	fields: &[],
+	permissions: &[CommercialUse, Modifications, Distribution, PrivateUse],
+	conditions: &[IncludeCopyright, DocumentChanges, DiscloseSource],
+	limitations: &[Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 The LaTeX Project Public License
 =-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
 
@@ -416,6 +429,4 @@ Important Recommendations
     impossible for the licensee to determine what is considered by you
     to comprise the Work and, in such a case, the licensee would be
     entitled to make reasonable conjectures as to which files comprise
-    the Work."#
-		.to_owned()
-}
+    the Work."#;
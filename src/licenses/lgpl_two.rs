@@ -1,7 +1,25 @@
 // GNU Lesser General Public License v2.1 - https://choosealicense.com/licenses/lgpl-2.1
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "lgpl_two",
+	name: "GNU Lesser General Public License v2.1",
+	spdx: "LGPL-2.1-only",
+	fields: &[],
+	permissions: &[CommercialUse, Modifications, Distribution, PrivateUse],
+	conditions: &[
+		IncludeCopyright,
+		DiscloseSource,
+		DocumentChanges,
+		SameLicenseLibrary,
+	],
+	limitations: &[Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 GNU LESSER GENERAL PUBLIC LICENSE
 Version 2.1, February 1999
 
@@ -505,6 +523,22 @@ Hacker.
 <signature of Ty Coon>, 1 April 1990
 Ty Coon, President of Vice
 
-That's all there is to it!"#
-		.to_owned()
-}
+That's all there is to it!"#;
+
+pub const NOTICE: &str = r#"
+{{project}}
+Copyright (C) {{year}} {{holders}}
+
+This library is free software; you can redistribute it and/or
+modify it under the terms of the GNU Lesser General Public
+License as published by the Free Software Foundation, version 2.1
+of the License.
+
+This library is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+GNU Lesser General Public License for more details.
+
+You should have received a copy of the GNU Lesser General Public
+License along with this library; if not, write to the Free Software
+Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA."#;
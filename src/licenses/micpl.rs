@@ -1,7 +1,26 @@
 // Microsoft Public License - https://choosealicense.com/licenses/ms-pl
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "micpl",
+	name: "Microsoft Public License",
+	spdx: "MS-PL",
+	fields: &[],
+	permissions: &[
+		CommercialUse,
+		Modifications,
+		Distribution,
+		PatentUse,
+		PrivateUse,
+	],
+	conditions: &[IncludeCopyright],
+	limitations: &[TrademarkUse, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 Microsoft Public License (Ms-PL)
 
 This license governs use of the accompanying software. If you use the
@@ -53,6 +72,4 @@ directly on its contribution.
         may have additional consumer rights under your local laws which this
         license cannot change. To the extent permitted under your local laws, the
         contributors exclude the implied warranties of merchantability, fitness
-        for a particular purpose and non-infringement."#
-		.to_owned()
-}
+        for a particular purpose and non-infringement."#;
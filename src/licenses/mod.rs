@@ -35,7 +35,467 @@ pub mod odl;
 pub mod ofl;
 pub mod osl;
 pub mod postgres;
+pub mod proprietary;
+mod spdx_list;
 pub mod unl;
 pub mod upl;
 pub mod vim;
 pub mod zlib;
+
+use regex::{Captures, Regex};
+pub use spdx_list::{is_valid_spdx, normalize_spdx, unresolved_region};
+use std::sync::{Mutex, PoisonError};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Field {
+	Year,
+	Names,
+	Emails,
+	Project,
+	Url,
+}
+
+impl Field {
+	pub const ALL: [Field; 5] = [
+		Field::Year,
+		Field::Names,
+		Field::Emails,
+		Field::Project,
+		Field::Url,
+	];
+
+	pub fn label(&self) -> &'static str {
+		match self {
+			Field::Year => "Year",
+			Field::Names => "Name(s)",
+			Field::Emails => "Email(s)",
+			Field::Project => "Project Name",
+			Field::Url => "Project URL",
+		}
+	}
+
+	pub fn flag(&self) -> &'static str {
+		match self {
+			Field::Year => "-y or --year",
+			Field::Names => "-n, --name or --holder",
+			Field::Emails => "-e or --email",
+			Field::Project => "-p or --project",
+			Field::Url => "-u or --url",
+		}
+	}
+
+	pub fn key(&self) -> &'static str {
+		match self {
+			Field::Year => "year",
+			Field::Names => "holders",
+			Field::Emails => "emails",
+			Field::Project => "project",
+			Field::Url => "url",
+		}
+	}
+
+	pub fn parse(name: &str) -> Option<Field> {
+		match name {
+			"year" => Some(Field::Year),
+			"name" | "holder" | "holders" => Some(Field::Names),
+			"email" | "emails" => Some(Field::Emails),
+			"project" => Some(Field::Project),
+			"url" => Some(Field::Url),
+			_ => None,
+		}
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Rule {
+	CommercialUse,
+	Modifications,
+	Distribution,
+	PrivateUse,
+	PatentUse,
+	IncludeCopyright,
+	IncludeCopyrightSource,
+	DocumentChanges,
+	DiscloseSource,
+	NetworkUseDisclose,
+	SameLicense,
+	SameLicenseFile,
+	SameLicenseLibrary,
+	Liability,
+	TrademarkUse,
+	Warranty,
+}
+
+impl Rule {
+	pub const ALL: [Rule; 16] = [
+		Rule::CommercialUse,
+		Rule::Modifications,
+		Rule::Distribution,
+		Rule::PrivateUse,
+		Rule::PatentUse,
+		Rule::IncludeCopyright,
+		Rule::IncludeCopyrightSource,
+		Rule::DocumentChanges,
+		Rule::DiscloseSource,
+		Rule::NetworkUseDisclose,
+		Rule::SameLicense,
+		Rule::SameLicenseFile,
+		Rule::SameLicenseLibrary,
+		Rule::Liability,
+		Rule::TrademarkUse,
+		Rule::Warranty,
+	];
+
+	pub fn label(&self) -> &'static str {
+		match self {
+			Rule::CommercialUse => "Commercial use",
+			Rule::Modifications => "Modification",
+			Rule::Distribution => "Distribution",
+			Rule::PrivateUse => "Private use",
+			Rule::PatentUse => "Patent use",
+			Rule::IncludeCopyright => "License and copyright notice",
+			Rule::IncludeCopyrightSource => "License and copyright notice for source",
+			Rule::DocumentChanges => "State changes",
+			Rule::DiscloseSource => "Disclose source",
+			Rule::NetworkUseDisclose => "Network use is distribution",
+			Rule::SameLicense => "Same license",
+			Rule::SameLicenseFile => "Same license (file)",
+			Rule::SameLicenseLibrary => "Same license (library)",
+			Rule::Liability => "Liability",
+			Rule::TrademarkUse => "Trademark use",
+			Rule::Warranty => "Warranty",
+		}
+	}
+
+	pub fn key(&self) -> &'static str {
+		match self {
+			Rule::CommercialUse => "commercial-use",
+			Rule::Modifications => "modifications",
+			Rule::Distribution => "distribution",
+			Rule::PrivateUse => "private-use",
+			Rule::PatentUse => "patent-use",
+			Rule::IncludeCopyright => "include-copyright",
+			Rule::IncludeCopyrightSource => "include-copyright--source",
+			Rule::DocumentChanges => "document-changes",
+			Rule::DiscloseSource => "disclose-source",
+			Rule::NetworkUseDisclose => "network-use-disclose",
+			Rule::SameLicense => "same-license",
+			Rule::SameLicenseFile => "same-license--file",
+			Rule::SameLicenseLibrary => "same-license--library",
+			Rule::Liability => "liability",
+			Rule::TrademarkUse => "trademark-use",
+			Rule::Warranty => "warranty",
+		}
+	}
+
+	pub fn parse(key: &str) -> Option<Rule> {
+		Rule::ALL.into_iter().find(|rule| rule.key() == key)
+	}
+
+	pub fn description(&self, limitation: bool) -> &'static str {
+		match self {
+			Rule::CommercialUse => "The licensed material and derivatives may be used for commercial purposes",
+			Rule::Modifications => "The licensed material may be modified",
+			Rule::Distribution => "The licensed material may be distributed",
+			Rule::PrivateUse => "The licensed material may be used and modified in private",
+			Rule::PatentUse if limitation => "This license explicitly states that it does NOT grant any rights in the patents of contributors",
+			Rule::PatentUse => "This license provides an express grant of patent rights from contributors",
+			Rule::IncludeCopyright => "A copy of the license and copyright notice must be included with the licensed material",
+			Rule::IncludeCopyrightSource => "A copy of the license and copyright notice must be included with the licensed material in source form, but is not required for binaries",
+			Rule::DocumentChanges => "Changes made to the licensed material must be documented",
+			Rule::DiscloseSource => "Source code must be made available when the licensed material is distributed",
+			Rule::NetworkUseDisclose => "Users who interact with the licensed material via network are given the right to receive a copy of the source code",
+			Rule::SameLicense => "Modifications must be released under the same license when distributing the licensed material",
+			Rule::SameLicenseFile => "Modifications of existing files must be released under the same license when distributing the licensed material",
+			Rule::SameLicenseLibrary => "Modifications must be released under the same license when distributing the licensed material, but this may not apply to works that use it as a library",
+			Rule::Liability => "This license includes a limitation of liability",
+			Rule::TrademarkUse => "This license explicitly states that it does NOT grant trademark rights",
+			Rule::Warranty => "This license explicitly states that it does NOT provide any warranty",
+		}
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Kind {
+	PublicDomain,
+	Permissive,
+	WeakCopyleft,
+	Copyleft,
+	Proprietary,
+	Unknown,
+}
+
+pub struct Gnu {
+	pub file: &'static str,
+	pub companion: Option<&'static License>,
+	pub notice: &'static str,
+}
+
+pub struct License {
+	pub id: &'static str,
+	pub name: &'static str,
+	pub spdx: &'static str,
+	pub fields: &'static [Field],
+	pub permissions: &'static [Rule],
+	pub conditions: &'static [Rule],
+	pub limitations: &'static [Rule],
+	pub text: &'static str,
+	pub languages: &'static [(&'static str, &'static str)],
+}
+
+impl License {
+	pub fn kind(&self) -> Kind {
+		if self.permissions.is_empty() && self.conditions.is_empty() && self.limitations.is_empty()
+		{
+			Kind::Unknown
+		} else if self.permissions.is_empty() {
+			Kind::Proprietary
+		} else if self.conditions.contains(&Rule::SameLicense) {
+			Kind::Copyleft
+		} else if self.conditions.contains(&Rule::SameLicenseFile)
+			|| self.conditions.contains(&Rule::SameLicenseLibrary)
+		{
+			Kind::WeakCopyleft
+		} else if self.conditions.is_empty() {
+			Kind::PublicDomain
+		} else {
+			Kind::Permissive
+		}
+	}
+
+	pub fn patent_grant(&self) -> bool {
+		self.permissions.contains(&Rule::PatentUse)
+	}
+
+	pub fn network_use(&self) -> bool {
+		self.conditions.contains(&Rule::NetworkUseDisclose)
+	}
+
+	pub fn attribution(&self) -> bool {
+		self.conditions.contains(&Rule::IncludeCopyright)
+			|| self.conditions.contains(&Rule::IncludeCopyrightSource)
+	}
+
+	pub fn osi_approved(&self) -> bool {
+		spdx::license_id(self.spdx).is_some_and(|id| id.is_osi_approved())
+	}
+
+	pub fn fsf_libre(&self) -> bool {
+		spdx::license_id(self.spdx).is_some_and(|id| id.is_fsf_free_libre())
+	}
+
+	pub fn rank(&self, term: &str) -> Option<u8> {
+		let words = |text: &str| {
+			text.split_whitespace()
+				.collect::<Vec<&str>>()
+				.join(" ")
+				.to_lowercase()
+		};
+		let term = words(term);
+
+		if [self.id, self.spdx]
+			.into_iter()
+			.any(|value| value.eq_ignore_ascii_case(&term))
+		{
+			return Some(0);
+		}
+
+		if [self.id, self.name, self.spdx]
+			.into_iter()
+			.any(|value| words(value).contains(&term))
+		{
+			return Some(1);
+		}
+
+		let phrase = Regex::new(&format!(r"(?:^|\W){}(?:\W|$)", regex::escape(&term))).ok()?;
+
+		(term.contains(' ') && phrase.is_match(&words(self.text))).then_some(2)
+	}
+
+	pub fn langs(&self) -> Vec<&'static str> {
+		match self.languages {
+			[] => vec!["en"],
+			languages => languages.iter().map(|(lang, _)| *lang).collect(),
+		}
+	}
+
+	pub fn translation(&self, lang: Option<&str>) -> Option<&'static str> {
+		match lang {
+			None => Some(self.text),
+			Some(lang) if self.languages.is_empty() => (lang == "en").then_some(self.text),
+			Some(lang) => self
+				.languages
+				.iter()
+				.find(|(code, _)| code.eq_ignore_ascii_case(lang))
+				.map(|(_, text)| *text),
+		}
+	}
+
+	pub fn gnu(&self) -> Option<Gnu> {
+		let (file, companion, notice) = match self.spdx {
+			"AGPL-3.0-only" => ("COPYING", None, agpl::NOTICE),
+			"GPL-2.0-only" => ("COPYING", None, gpl_two::NOTICE),
+			"GPL-3.0-only" => ("COPYING", None, gpl_three::NOTICE),
+			"LGPL-2.1-only" => ("COPYING.LESSER", None, lgpl_two::NOTICE),
+			"LGPL-3.0-only" => (
+				"COPYING.LESSER",
+				Some(&gpl_three::LICENSE),
+				lgpl_three::NOTICE,
+			),
+			_ => return None,
+		};
+
+		Some(Gnu {
+			file,
+			companion,
+			notice,
+		})
+	}
+
+	pub fn render(&self, value: impl Fn(Field) -> String) -> Result<String, String> {
+		self.render_text(self.text, value)
+	}
+
+	pub fn render_text(
+		&self,
+		text: &str,
+		value: impl Fn(Field) -> String,
+	) -> Result<String, String> {
+		render(text, |key| {
+			self.fields
+				.iter()
+				.find(|field| field.key() == key)
+				.map(|field| value(*field))
+		})
+	}
+}
+
+pub const LICENSES: [&License; 42] = [
+	&afl::LICENSE,
+	&agpl::LICENSE,
+	&apache_two::LICENSE,
+	&art::LICENSE,
+	&boost::LICENSE,
+	&bsd_clear::LICENSE,
+	&bsd_four::LICENSE,
+	&bsd_three::LICENSE,
+	&bsd_two::LICENSE,
+	&bsd_zero::LICENSE,
+	&cc::LICENSE,
+	&cc_sa::LICENSE,
+	&cc_zero::LICENSE,
+	&cecill::LICENSE,
+	&ecl::LICENSE,
+	&eclipse_one::LICENSE,
+	&eclipse_two::LICENSE,
+	&eu_one::LICENSE,
+	&eu_two::LICENSE,
+	&fdl::LICENSE,
+	&gpl_three::LICENSE,
+	&gpl_two::LICENSE,
+	&isc::LICENSE,
+	&latex::LICENSE,
+	&lgpl_three::LICENSE,
+	&lgpl_two::LICENSE,
+	&micpl::LICENSE,
+	&mit::LICENSE,
+	&mit_na::LICENSE,
+	&mozpl::LICENSE,
+	&mrl::LICENSE,
+	&mulpl::LICENSE,
+	&ncsa::LICENSE,
+	&odl::LICENSE,
+	&ofl::LICENSE,
+	&osl::LICENSE,
+	&postgres::LICENSE,
+	&proprietary::LICENSE,
+	&unl::LICENSE,
+	&upl::LICENSE,
+	&vim::LICENSE,
+	&zlib::LICENSE,
+];
+
+static CUSTOM: Mutex<Vec<&'static License>> = Mutex::new(Vec::new());
+
+pub fn register_license(license: License) -> &'static License {
+	let license = Box::leak(Box::new(license));
+
+	CUSTOM
+		.lock()
+		.unwrap_or_else(PoisonError::into_inner)
+		.push(license);
+
+	license
+}
+
+pub fn all_licenses() -> Vec<&'static License> {
+	let mut licenses = LICENSES.to_vec();
+
+	licenses.extend(CUSTOM.lock().unwrap_or_else(PoisonError::into_inner).iter());
+
+	licenses
+}
+
+pub fn find_license(id: &str) -> Option<&'static License> {
+	let licenses = all_licenses();
+
+	licenses
+		.iter()
+		.find(|license| license.id == id)
+		.or_else(|| LICENSES.iter().find(|license| license.spdx == id))
+		.copied()
+		.or_else(|| spdx_list::find_spdx(id))
+}
+
+pub(crate) fn leak(string: String) -> &'static str {
+	Box::leak(string.into_boxed_str())
+}
+
+pub(crate) fn infer_fields(text: &str) -> Vec<Field> {
+	let mut fields = Vec::new();
+
+	for caps in Regex::new(r"\{\{\s*([a-z_]+)\s*\}\}")
+		.unwrap()
+		.captures_iter(text)
+	{
+		if let Some(field) = Field::ALL.into_iter().find(|field| field.key() == &caps[1]) {
+			if !fields.contains(&field) {
+				fields.push(field);
+			}
+		}
+	}
+
+	fields
+}
+
+pub fn render(template: &str, value: impl Fn(&str) -> Option<String>) -> Result<String, String> {
+	let mut unresolved = None;
+
+	let text = Regex::new(r"\{\{\s*([a-z_]+)\s*\}\}")
+		.unwrap()
+		.replace_all(template, |caps: &Captures| {
+			value(&caps[1]).unwrap_or_else(|| {
+				unresolved.get_or_insert_with(|| caps[0].to_owned());
+				String::new()
+			})
+		})
+		.into_owned();
+
+	match unresolved {
+		Some(placeholder) => Err(placeholder),
+		None => Ok(text),
+	}
+}
+
+pub fn strip_appendix(text: &str) -> &str {
+	let appendix =
+		Regex::new(r"(?m)^(?:END OF (?:THE )?TERMS AND CONDITIONS|条款结束)$|^ADDENDUM:")
+			.unwrap()
+			.find(text);
+
+	match appendix {
+		Some(end) if end.as_str() == "ADDENDUM:" => text[..end.start()].trim_end(),
+		Some(end) => &text[..end.end()],
+		None => text,
+	}
+}
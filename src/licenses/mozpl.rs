@@ -1,7 +1,26 @@
 // Mozilla Public License 2.0 - https://choosealicense.com/licenses/mpl-2.0/
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "mozpl",
+	name: "Mozilla Public License 2.0",
+	spdx: "MPL-2.0",
+	fields: &[],
+	permissions: &[
+		CommercialUse,
+		Modifications,
+		Distribution,
+		PatentUse,
+		PrivateUse,
+	],
+	conditions: &[DiscloseSource, IncludeCopyright, SameLicenseFile],
+	limitations: &[Liability, TrademarkUse, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 Mozilla Public License Version 2.0
 ==================================
 
@@ -374,6 +393,4 @@ Exhibit B - "Incompatible With Secondary Licenses" Notice
 ---------------------------------------------------------
 
     This Source Code Form is "Incompatible With Secondary Licenses", as
-    defined by the Mozilla Public License, v. 2.0."#
-		.to_owned()
-}
+    defined by the Mozilla Public License, v. 2.0."#;
@@ -1,7 +1,26 @@
 // Microsoft Reciprocal License - https://choosealicense.com/licenses/ms-rl
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "mrl",
+	name: "Microsoft Reciprocal License",
+	spdx: "MS-RL",
+	fields: &[],
+	permissions: &[
+		CommercialUse,
+		Modifications,
+		Distribution,
+		PatentUse,
+		PrivateUse,
+	],
+	conditions: &[DiscloseSource, IncludeCopyright, SameLicenseFile],
+	limitations: &[TrademarkUse, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 Microsoft Reciprocal License (Ms-RL)
 
 This license governs use of the accompanying software. If you use the
@@ -65,6 +84,4 @@ contribution.
         may have additional consumer rights under your local laws which this
         license cannot change. To the extent permitted under your local laws, the
         contributors exclude the implied warranties of merchantability, fitness
-        for a particular purpose and non-infringement."#
-		.to_owned()
-}
+        for a particular purpose and non-infringement."#;
@@ -1,17 +1,27 @@
 // University of Illinois/NCSA Open Source License - https://choosealicense.com/licenses/ncsa/
 
-pub fn get(year: String, names: Vec<String>, project: String, url: String) -> String {
-	let names = names.join(", ");
-
-	format!(
-		r#"
+use super::{Field::*, License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "ncsa",
+	name: "University of Illinois/NCSA Open Source License",
+	spdx: "NCSA",
+	fields: &[Year, Names, Project, Url],
+	permissions: &[CommercialUse, Modifications, Distribution, PrivateUse],
+	conditions: &[IncludeCopyright],
+	limitations: &[Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 University of Illinois/NCSA Open Source License
 
-Copyright (c) {} {}. All rights reserved.
+Copyright (c) {{year}} {{holders}}. All rights reserved.
 
-Developed by: {}
-              {}
-              {}
+Developed by: {{project}}
+              {{holders}}
+              {{url}}
 
 Permission is hereby granted, free of charge, to any person
 obtaining a copy of this software and associated documentation files
@@ -28,7 +38,7 @@ subject to the following conditions:
     notice, this list of conditions and the following disclaimers in the
     documentation and/or other materials provided with the distribution.
 
-* Neither the names of [fullname], [project] nor the names of its
+* Neither the names of {{holders}}, {{project}} nor the names of its
     contributors may be used to endorse or promote products derived from
     this Software without specific prior written permission.
 
@@ -38,7 +48,4 @@ FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
 CONTRIBUTORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
 LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
 OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS WITH
-THE SOFTWARE."#,
-		year, names, project, names, url
-	)
-}
+THE SOFTWARE."#;
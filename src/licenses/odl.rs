@@ -1,7 +1,20 @@
 // Open Data Commons Open Database License v1.0 - https://choosealicense.com/licenses/odbl-1.0
 
-pub fn get() -> String {
-	r#"
+use super::{License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "odl",
+	name: "Open Data Commons Open Database License v1.0",
+	spdx: "ODbL-1.0",
+	fields: &[],
+	permissions: &[CommercialUse, Modifications, Distribution, PrivateUse],
+	conditions: &[IncludeCopyright, DiscloseSource, SameLicense],
+	limitations: &[Liability, PatentUse, TrademarkUse, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 ## ODC Open Database License (ODbL)
 
 ### Preamble
@@ -541,6 +554,4 @@ sought to be enforced. If the standard suite of rights granted under
 applicable copyright law and Database Rights in the relevant
 jurisdiction includes additional rights not granted under this License,
 these additional rights are granted in this License in order to meet the
-terms of this License."#
-		.to_owned()
-}
+terms of this License."#;
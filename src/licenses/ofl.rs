@@ -1,9 +1,21 @@
 // SIL Open Font License 1.1 - https://choosealicense.com/licenses/ofl-1.1
 
-pub fn get(year: String, names: Vec<String>, emails: Vec<String>) -> String {
-	format!(
-		r#"
-Copyright (c) {} {} {}
+use super::{Field::*, License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "ofl",
+	name: "SIL Open Font License 1.1",
+	spdx: "OFL-1.1",
+	fields: &[Year, Names, Emails],
+	permissions: &[CommercialUse, Modifications, Distribution, PrivateUse],
+	conditions: &[IncludeCopyright, SameLicense],
+	limitations: &[Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
+Copyright (c) {{year}} {{holders}} {{emails}}
 
 This Font Software is licensed under the SIL Open Font License, Version 1.1.
 This license is copied below, and is also available with a FAQ at:
@@ -94,9 +106,4 @@ COPYRIGHT HOLDER BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,
 INCLUDING ANY GENERAL, SPECIAL, INDIRECT, INCIDENTAL, OR CONSEQUENTIAL
 DAMAGES, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
 FROM, OUT OF THE USE OR INABILITY TO USE THE FONT SOFTWARE OR FROM
-OTHER DEALINGS IN THE FONT SOFTWARE."#,
-		year,
-		names.join(", "),
-		emails.join(", ")
-	)
-}
+OTHER DEALINGS IN THE FONT SOFTWARE."#;
@@ -1,29 +1,36 @@
 // PostgreSQL License - https://choosealicense.com/licenses/postgresql
 
-pub fn get(year: String, names: Vec<String>) -> String {
-	let names = names.join(", ");
+use super::{Field::*, License, Rule::*};
 
-	format!(
-		r#"
+pub const LICENSE: License = License {
+	id: "postgres",
+	name: "PostgreSQL License",
+	spdx: "PostgreSQL",
+	fields: &[Year, Names],
+	permissions: &[CommercialUse, Modifications, Distribution, PrivateUse],
+	conditions: &[IncludeCopyright],
+	limitations: &[Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 PostgreSQL License
 
-Copyright (c) {} {}
+Copyright (c) {{year}} {{holders}}
 
 Permission to use, copy, modify, and distribute this software and its
 documentation for any purpose, without fee, and without a written agreement is
 hereby granted, provided that the above copyright notice and this paragraph
 and the following two paragraphs appear in all copies.
 
-IN NO EVENT SHALL {} BE LIABLE TO ANY PARTY FOR DIRECT, INDIRECT,
+IN NO EVENT SHALL {{holders}} BE LIABLE TO ANY PARTY FOR DIRECT, INDIRECT,
 SPECIAL, INCIDENTAL, OR CONSEQUENTIAL DAMAGES, INCLUDING LOST PROFITS, ARISING
-OUT OF THE USE OF THIS SOFTWARE AND ITS DOCUMENTATION, EVEN IF [fullname]
+OUT OF THE USE OF THIS SOFTWARE AND ITS DOCUMENTATION, EVEN IF {{holders}}
 HAS BEEN ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-{} SPECIFICALLY DISCLAIMS ANY WARRANTIES, INCLUDING, BUT NOT
+{{holders}} SPECIFICALLY DISCLAIMS ANY WARRANTIES, INCLUDING, BUT NOT
 LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A
 PARTICULAR PURPOSE. THE SOFTWARE PROVIDED HEREUNDER IS ON AN "AS IS" BASIS,
-AND [fullname] HAS NO OBLIGATIONS TO PROVIDE MAINTENANCE, SUPPORT, UPDATES,
-ENHANCEMENTS, OR MODIFICATIONS."#,
-		year, names, names, names
-	)
-}
+AND {{holders}} HAS NO OBLIGATIONS TO PROVIDE MAINTENANCE, SUPPORT, UPDATES,
+ENHANCEMENTS, OR MODIFICATIONS."#;
@@ -0,0 +1,40 @@
+// Proprietary License - https://spdx.github.io/spdx-spec/v2.3/other-licensing-information-detected/
+
+use super::{Field::*, License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "proprietary",
+	name: "Proprietary License",
+	spdx: "LicenseRef-Proprietary",
+	fields: &[Year, Names],
+	permissions: &[],
+	conditions: &[],
+	limitations: &[Liability, TrademarkUse, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
+Proprietary License
+
+Copyright (c) {{year}} {{holders}}
+All rights reserved.
+
+This software and associated documentation files (the "Software") are
+proprietary and confidential. No part of the Software may be used, copied,
+modified, merged, published, distributed, sublicensed, or sold, in whole or in
+part, without the prior written permission of {{holders}}.
+
+The Software and all information contained herein remain the property of
+{{holders}}. The intellectual and technical concepts contained herein are
+confidential, may be covered by patents or patents in process, and are
+protected by trade secret or copyright law. Unauthorized copying or disclosure
+of the Software, via any medium, is strictly prohibited.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE."#;
@@ -0,0 +1,167 @@
+// SPDX License List - https://spdx.org/licenses
+
+use super::{infer_fields, leak, License};
+use regex::Regex;
+use std::{
+	ptr,
+	sync::{Mutex, PoisonError},
+};
+
+static CACHE: Mutex<Vec<(&'static License, Option<&'static str>)>> = Mutex::new(Vec::new());
+
+const PLACEHOLDERS: [(&str, &str); 26] = [
+	("<year>", "{{year}}"),
+	("<Year>", "{{year}}"),
+	("<YEAR>", "{{year}}"),
+	("<yyyy>", "{{year}}"),
+	("[year]", "{{year}}"),
+	("[Year]", "{{year}}"),
+	("[YEAR]", "{{year}}"),
+	("[yyyy]", "{{year}}"),
+	("<owner>", "{{holders}}"),
+	("<OWNER>", "{{holders}}"),
+	("<copyright holder>", "{{holders}}"),
+	("<copyright holders>", "{{holders}}"),
+	("<COPYRIGHT HOLDER>", "{{holders}}"),
+	("<COPYRIGHT HOLDERS>", "{{holders}}"),
+	("<HOLDERS>", "{{holders}}"),
+	("<AUTHOR>", "{{holders}}"),
+	("<name of author>", "{{holders}}"),
+	("<ORGANIZATION>", "{{holders}}"),
+	("[name of copyright owner]", "{{holders}}"),
+	("[name of copyright holder]", "{{holders}}"),
+	("[copyright holders]", "{{holders}}"),
+	("<program>", "{{project}}"),
+	("<PRODUCT>", "{{project}}"),
+	("[Software Name]", "{{project}}"),
+	("[SOFTWARE NAME]", "{{project}}"),
+	("[NAME]", "{{holders}}"),
+];
+
+const PARAMETERS: [(&str, &str); 1] = [(
+	"BUSL-1.1",
+	"Parameters
+
+Licensor:             {{holders}}
+Licensed Work:        {{project}}
+Additional Use Grant: None
+Change Date:          [Change Date]
+Change License:       [Change License]
+
+Notice
+
+The Business Source License (this document, or the “License”) is not an Open
+Source license. However, the Licensed Work will eventually be made available
+under an Open Source License, as stated in this License.
+
+",
+)];
+
+fn variable_region(text: &str) -> Option<&str> {
+	let lines = text.lines().collect::<Vec<&str>>();
+	let copyright = Regex::new(r"^\s*Copyright\b.*\b\d{4}\b").unwrap();
+	let parameter = Regex::new(r"^[A-Z][A-Za-z ]+:\s+(\[[^\]]+\])$").unwrap();
+
+	lines.iter().enumerate().find_map(|(i, line)| {
+		if copyright.is_match(line)
+			&& !line.contains("{{")
+			&& !lines[i..lines.len().min(i + 4)]
+				.iter()
+				.any(|line| line.contains("verbatim"))
+		{
+			return Some(line.trim());
+		}
+
+		Some(parameter.captures(line)?.get(1)?.as_str())
+	})
+}
+
+pub fn unresolved_region(license: &License) -> Option<&'static str> {
+	CACHE
+		.lock()
+		.unwrap_or_else(PoisonError::into_inner)
+		.iter()
+		.find(|(cached, _)| ptr::eq(*cached, license))
+		.and_then(|(_, region)| *region)
+}
+
+pub fn find_spdx(id: &str) -> Option<&'static License> {
+	let (name, full_name, _) = spdx::identifiers::LICENSES
+		.iter()
+		.find(|license| license.0.eq_ignore_ascii_case(id))?;
+
+	let mut cache = CACHE.lock().unwrap_or_else(PoisonError::into_inner);
+
+	if let Some((license, _)) = cache.iter().find(|(license, _)| license.id == *name) {
+		return Some(license);
+	}
+
+	let mut text = spdx::license_id(name)?.text().trim().to_owned();
+
+	for (placeholder, key) in PLACEHOLDERS {
+		text = text.replace(placeholder, key);
+	}
+
+	if let Some((_, parameters)) = PARAMETERS.iter().find(|(id, _)| id == name) {
+		text = text.replacen("\nTerms\n", &format!("\n{}Terms\n", parameters), 1);
+	}
+
+	let text = leak(format!("\n{}", text));
+
+	let license = Box::leak(Box::new(License {
+		id: name,
+		name: full_name,
+		spdx: name,
+		fields: Box::leak(infer_fields(text).into_boxed_slice()),
+		permissions: &[],
+		conditions: &[],
+		limitations: &[],
+		languages: &[],
+		text,
+	}));
+
+	cache.push((license, variable_region(text)));
+
+	Some(license)
+}
+
+const DEPRECATED: [(&str, &str); 4] = [
+	("BSD-2-Clause-FreeBSD", "BSD-2-Clause"),
+	("BSD-2-Clause-NetBSD", "BSD-2-Clause"),
+	("Nunit", "zlib-acknowledgement"),
+	("StandardML-NJ", "SMLNJ"),
+];
+
+pub fn is_valid_spdx(id: &str) -> bool {
+	id.starts_with("LicenseRef-")
+		|| spdx::license_id(id)
+			.is_some_and(|license| !license.is_deprecated() && license.name == id)
+}
+
+pub fn normalize_spdx(id: &str) -> Option<&'static str> {
+	let id = id.trim();
+
+	let later = id.strip_suffix('+');
+	let license = spdx::identifiers::LICENSES
+		.iter()
+		.find(|license| license.0.eq_ignore_ascii_case(later.unwrap_or(id)))?
+		.0;
+
+	if later.is_some() {
+		return Some(
+			spdx::license_id(&format!("{}-or-later", license))
+				.map_or(license, |license| license.name),
+		);
+	}
+
+	if !spdx::license_id(license)?.is_deprecated() {
+		return Some(license);
+	}
+
+	DEPRECATED
+		.into_iter()
+		.find(|(deprecated, _)| *deprecated == license)
+		.map(|(_, replacement)| replacement)
+		.or_else(|| spdx::license_id(&format!("{}-only", license)).map(|license| license.name))
+		.or(Some(license))
+}
@@ -1,9 +1,27 @@
 // Universal Permissive License v1.0 - https://choosealicense.com/licenses/upl-1.0
 
-pub fn get(year: String, names: Vec<String>) -> String {
-	format!(
-		r#"
-Copyright (c) {} {}
+use super::{Field::*, License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "upl",
+	name: "Universal Permissive License v1.0",
+	spdx: "UPL-1.0",
+	fields: &[Year, Names],
+	permissions: &[
+		CommercialUse,
+		Modifications,
+		Distribution,
+		PatentUse,
+		PrivateUse,
+	],
+	conditions: &[IncludeCopyright],
+	limitations: &[Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
+Copyright (c) {{year}} {{holders}}
 
 The Universal Permissive License (UPL), Version 1.0
 
@@ -37,8 +55,4 @@ FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
 AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
 LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
 OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
-SOFTWARE."#,
-		year,
-		names.join(", ")
-	)
-}
+SOFTWARE."#;
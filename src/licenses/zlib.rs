@@ -1,11 +1,23 @@
 // zlib License - https://choosealicense.com/licenses/zlib
 
-pub fn get(year: String, names: Vec<String>) -> String {
-	format!(
-		r#"
+use super::{Field::*, License, Rule::*};
+
+pub const LICENSE: License = License {
+	id: "zlib",
+	name: "zlib License",
+	spdx: "Zlib",
+	fields: &[Year, Names],
+	permissions: &[CommercialUse, Modifications, Distribution, PrivateUse],
+	conditions: &[IncludeCopyright, DocumentChanges],
+	limitations: &[Liability, Warranty],
+	text: TEXT,
+	languages: &[],
+};
+
+pub const TEXT: &str = r#"
 zlib License
 
-(C) {} {}
+(C) {{year}} {{holders}}
 
 This software is provided 'as-is', without any express or implied
 warranty. In no event will the authors be held liable for any damages
@@ -21,8 +33,4 @@ freely, subject to the following restrictions:
     appreciated but is not required.
 2. Altered source versions must be plainly marked as such, and must not be
     misrepresented as being the original software.
-3. This notice may not be removed or altered from any source distribution."#,
-		year,
-		names.join(", ")
-	)
-}
+3. This notice may not be removed or altered from any source distribution."#;
@@ -1,228 +1,835 @@
+use clap::{
+	error::{ContextKind, ErrorKind},
+	ColorChoice, FromArgMatches,
+};
+use cli::{Cli, ColorMode, Command, Format, HeaderArgs, InfoArgs, NewArgs, WriteArgs};
 use colored::Colorize;
-use licer::{get_license, write_pkg, CliError};
-use std::{fs::write, path::PathBuf};
+use json::{object, JsonValue};
+use licer::{
+	backup_file, check_existing, check_license, collect_warnings, detect_license, find_license,
+	find_license_file, insert_header, load_template, load_templates, read_config,
+	read_manifest_license, render_header, render_license, render_notice, resolve_values,
+	take_warnings, write_members, write_pkg, CommentStyle, Config, Existing, Field, HolderFormat,
+	LicerError, ManifestUpdate, Project, Values,
+};
+use std::{
+	fmt,
+	io::{self, stderr, stdin, stdout, IsTerminal, Write},
+	path::{Path, PathBuf},
+	process::exit,
+	sync::atomic::{AtomicBool, Ordering},
+};
+
+macro_rules! out {
+	($($arg:tt)*) => {
+		$crate::write_out(format_args!($($arg)*))
+	};
+}
+
+macro_rules! outln {
+	() => {
+		out!("\n")
+	};
+	($($arg:tt)*) => {
+		out!("{}\n", format_args!($($arg)*))
+	};
+}
+
+mod cli;
+mod info;
+mod wizard;
+
+static JSON: AtomicBool = AtomicBool::new(false);
+static QUIET: AtomicBool = AtomicBool::new(false);
+static VERBOSE: AtomicBool = AtomicBool::new(false);
+
+fn write_out(args: fmt::Arguments) {
+	if let Err(error) = stdout().lock().write_fmt(args) {
+		if error.kind() == io::ErrorKind::BrokenPipe {
+			exit(0)
+		}
+	}
+}
+
+fn json() -> bool {
+	JSON.load(Ordering::Relaxed)
+}
+
+fn quiet() -> bool {
+	QUIET.load(Ordering::Relaxed)
+}
+
+fn verbose() -> bool {
+	VERBOSE.load(Ordering::Relaxed)
+}
+
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+	args.iter()
+		.enumerate()
+		.rev()
+		.find_map(|(i, arg)| match arg.strip_prefix(flag) {
+			Some("") => args.get(i + 1).map(|value| value.as_str()),
+			Some(value) => value.strip_prefix('='),
+			None => None,
+		})
+}
+
+fn set_color(color: ColorMode) {
+	match color {
+		ColorMode::Auto => colored::control::unset_override(),
+		ColorMode::Always => colored::control::set_override(true),
+		ColorMode::Never => colored::control::set_override(false),
+	}
+}
+
+fn error_json(error: &LicerError) -> JsonValue {
+	object! {
+		kind: error.kind(),
+		message: error.to_string(),
+	}
+}
+
+fn flush_warnings() {
+	if !json() {
+		print_warnings(take_warnings());
+	}
+}
+
+fn print_warnings(warnings: Vec<LicerError>) {
+	for warning in warnings {
+		match warning {
+			LicerError::ExistingLicense { path } => eprintln!(
+				"{} Skipped '{}' as it already exists! Use the --force flag to overwrite it!",
+				"WARN".yellow().bold(),
+				path.to_string_lossy()
+			),
+			error => eprintln!("{} {}", "WARN".yellow().bold(), error),
+		}
+	}
+}
+
+fn throw(error: LicerError) -> ! {
+	if json() {
+		outln!("{}", object! { error: error_json(&error) }.pretty(2));
+	} else {
+		flush_warnings();
+		eprintln!("{} {}", "ERROR".red().bold(), error);
+	}
+
+	exit(error.exit_code())
+}
+
+fn success(message: String) {
+	flush_warnings();
+
+	if !json() && !quiet() {
+		outln!("{} {}", "SUCCESS".green().bold(), message);
+	}
+}
+
+fn usage_error(error: clap::Error) -> LicerError {
+	let context = |kind: ContextKind| {
+		error
+			.get(kind)
+			.map(|value| value.to_string())
+			.unwrap_or_default()
+	};
+	let message = error.render().to_string();
+	let message = message
+		.trim_start_matches("error: ")
+		.split("\n\n")
+		.next()
+		.unwrap_or_default()
+		.split_whitespace()
+		.collect::<Vec<&str>>()
+		.join(" ");
+	let mut chars = message.chars();
+	let reason = match chars.next() {
+		Some(first) => format!("{}{}!", first.to_uppercase(), chars.as_str()),
+		None => String::new(),
+	};
+
+	match error.kind() {
+		ErrorKind::UnknownArgument => LicerError::UnknownFlag {
+			flag: context(ContextKind::InvalidArg),
+		},
+		ErrorKind::InvalidSubcommand => LicerError::UnknownArg {
+			arg: context(ContextKind::InvalidSubcommand),
+		},
+		ErrorKind::MissingRequiredArgument | ErrorKind::TooFewValues => LicerError::MissingArg {
+			arg: context(ContextKind::InvalidArg),
+			reason,
+		},
+		_ => LicerError::InvalidFlag {
+			flag: context(ContextKind::InvalidArg),
+			reason,
+		},
+	}
+}
 
 fn main() {
 	let args: Vec<String> = std::env::args().collect();
 
-	match (args.get(1), &"-h".to_owned()) {
-		(None, arg) | (Some(arg), _) if arg == "-h" || arg == "--help" => {
-			println!(
-				r#"
-Usage: licer <license>
-[-d | --directory <directory>]
-[-e | --email <email> (repeated)]
-[-f | --file <file name>]
-[-h | --help (independent)]
-[-n | --name <name> (repeated)]
-[-p | --project <project name>]
-[-u | --url <project url>]
-[-v | --version (independent)]
-[-y | --year <year>]
-
-Repeated flags are used to define multiple authors which will be inserted in-order from left to right
-Example: licer mit --name Zahtec --email email@example.com -n Fireship -e email@fireship.io
-The name "Zahtec" will be associated with the email "email@example.com" and "Fireship" "email@fireship.io"
-
-Licer will automatically grab the year using the current year set on the operating system
-Licer will automatically grab your name and email via the local or global git config
-Licer will automatically grab the project name and url via a Cargo.toml (Rust), package.json (Node.js), or pyproject.toml (Python)
-Licer will automatically write the license type to a Cargo.toml (Rust), package.json (Node.js), or pyproject.toml (Python)
-
-List of licenses:
-
-Licer Name     Full Name                                                     Info Required
-------------------------------------------------------------------------------------------------------------------
-afl            Academic Free License                                         None
-agpl           GNU Affero General Public License v3.0                        None
-apache_two     Apache License 2.0                                            None
-art            Artistic License 2.0                                          None
-boost          Boost Software License 1.0                                    None
-bsd_clear      BSD 3-Clause Clear License                                    Year, Name(s)
-bsd_four       BSD 4-Clause “Original” or “Old” License                      Year, Name(s)
-bsd_three      BSD 3-Clause “New” or “Revised” License                       Year, Name(s)
-bsd_two        BSD 2-Clause “Simplified” License                             Year, Name(s)
-bsd_zero       BSD Zero Clause License                                       Year, Name(s)
-cc             Creative Commons Attribution 4.0 International                None
-cc_sa          Creative Commons Attribution Share Alike 4.0 International    None
-cc_zero        Creative Commons Zero v1.0 Universal                          None
-cecill         CeCILL Free Software License Agreement v2.1                   None
-ecl            Educational Community License v2.0                            None
-eclipse_one    Eclipse Public License 1.0                                    None
-eclipse_two    Eclipse Public License 2.0                                    None
-eu_one         European Union Public License 1.1                             None
-eu_two         European Union Public License 1.2                             None
-fdl            GNU Free Documentation License v1.3                           None
-gpl_three      GNU General Public License v3.0                               None
-gpl_two        GNU General Public License v2.0                               None
-isc            ISC License                                                   Year, Name(s)
-latex          LaTeX Project Public License v1.3c                            None
-lgpl_three     GNU Lesser General Public License v3.0                        None
-lgpl_two       GNU Lesser General Public License v2.1                        None
-micpl          Microsoft Public License                                      None
-mit            MIT License                                                   Year, Name(s)
-mit_na         MIT No Attribution                                            Year, Name(s)
-mozpl          Mozilla Public License 2.0                                    None
-mrl            Microsoft Reciprocal License                                  None
-mulpl          Mulan Permissive Software License, Version 2                  None
-ncsa           University of Illinois/NCSA Open Source License               Year, Name(s), Project Name, Project URL
-odl            Open Data Commons Open Database License v1.0                  None
-ofl            SIL Open Font License 1.1                                     Year, Name(s), Email(s)
-osl            Open Software License 3.0                                     None
-postgres       PostgreSQL License                                            Year, Name(s)
-unl            The Unlicense                                                 None
-upl            Universal Permissive License v1.0                             Year, Name(s)
-vim            Vim License                                                   None
-zlib           zlib License                                                  Year, Name(s)
-
-Version: {}"#,
-				env!("CARGO_PKG_VERSION")
-			);
+	JSON.store(
+		flag_value(&args, "--format") == Some("json"),
+		Ordering::Relaxed,
+	);
+	QUIET.store(
+		args.iter().any(|arg| arg == "-q" || arg == "--quiet"),
+		Ordering::Relaxed,
+	);
+
+	let (mut report, warnings) = collect_warnings(|| run(args));
+
+	if !json() {
+		print_warnings(warnings);
+
+		return;
+	}
+
+	if !report.is_null() {
+		report["warnings"] = warnings.iter().map(error_json).collect::<Vec<_>>().into();
+
+		outln!("{}", report.pretty(2));
+	}
+}
 
-			return;
+fn run(mut args: Vec<String>) -> JsonValue {
+	let root = Project::current();
+	let config = read_config(&root);
+
+	for dir in &config.templates {
+		load_templates(&root, dir);
+	}
+
+	flush_warnings();
+
+	let color = match flag_value(&args, "--color") {
+		Some("always") => ColorChoice::Always,
+		Some("never") => ColorChoice::Never,
+		_ => ColorChoice::Auto,
+	};
+	let command = cli::command().color(color);
+
+	let mut first = 1;
+
+	while let Some(arg) = args.get(first).map(|arg| arg.as_str()) {
+		first += match arg {
+			"--format" | "--color" => 2,
+			"-q" | "--quiet" | "--verbose" => 1,
+			arg if arg.starts_with("--format=") || arg.starts_with("--color=") => 1,
+			_ => break,
+		};
+	}
+
+	if let Some(arg) = args.get(first) {
+		let known = command
+			.get_subcommands()
+			.any(|subcommand| subcommand.get_name() == arg)
+			|| ["help", "-h", "--help", "-v", "--version"].contains(&arg.as_str());
+
+		if !known {
+			args.insert(1, "new".to_owned());
+		}
+	}
+
+	let matches = command.try_get_matches_from(args).unwrap_or_else(|error| {
+		match json() && error.use_stderr() {
+			true => throw(usage_error(error)),
+			false => error.exit(),
 		}
-		(Some(arg), _) if arg == "-v" || arg == "--version" => {
-			println!("Licer v{}", env!("CARGO_PKG_VERSION"));
+	});
+	let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|error| error.exit());
+
+	JSON.store(cli.format == Format::Json, Ordering::Relaxed);
+	QUIET.store(cli.quiet, Ordering::Relaxed);
+	VERBOSE.store(cli.verbose, Ordering::Relaxed);
+	set_color(cli.color);
+
+	match cli.command {
+		Some(Command::New(args)) => new(&root, config, args, false),
+		Some(Command::Init(write)) => new(&root, config, NewArgs::init(write), true),
+		Some(Command::Check { file }) => check(&root, file),
+		Some(Command::Detect { file }) => detect(&root, file),
+		Some(Command::List(args)) => info::list(args),
+		Some(Command::Search { term }) => info::search(&term),
+		Some(Command::Info { license }) => info::info(&license),
+		Some(Command::Compare { licenses }) => info::compare(&licenses),
+		Some(Command::Header(args)) => header(&root, config, args),
+		Some(Command::Completions { shell }) => {
+			cli::completions(shell);
+			JsonValue::Null
+		}
+		Some(Command::Manpage { directory }) => {
+			cli::manpages(directory.as_deref()).unwrap_or_else(|error| throw(error))
+		}
+		None if config.license.is_some() => new(&root, config, NewArgs::default(), false),
+		None if stdin().is_terminal() => new(&root, config, NewArgs::default(), true),
+		None => {
+			cli::command().print_help().unwrap_or_default();
+			JsonValue::Null
+		}
+	}
+}
+
+fn confirm_overwrite(root: &Project, path: &Path, text: &str, force: bool) -> bool {
+	let license = match check_existing(root, path, text) {
+		Existing::Different { license } if !force => license,
+		_ => return true,
+	};
+
+	flush_warnings();
+
+	eprintln!(
+		"{} A license already exists at '{}' and {}!",
+		"WARN".yellow().bold(),
+		path.to_str().unwrap(),
+		match license {
+			Some(license) => format!("appears to be '{}'", license),
+			None => "does not match any known license".to_owned(),
+		}
+	);
+
+	if !stdin().is_terminal() {
+		return false;
+	}
 
-			return;
+	eprint!("Overwrite it? [y/N] ");
+	stderr().flush().ok();
+
+	let mut answer = String::new();
+
+	stdin().read_line(&mut answer).is_ok()
+		&& matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+fn default_license(root: &Project, config: &Config) -> String {
+	config
+		.license
+		.to_owned()
+		.or_else(|| {
+			read_manifest_license(root)
+				.and_then(|license| find_license(&license))
+				.map(|license| license.id.to_owned())
+		})
+		.unwrap_or_else(|| {
+			throw(LicerError::MissingArg {
+				arg: "license".to_owned(),
+				reason:
+					"Please provide the license or set a default one in a config file or manifest!"
+						.to_owned(),
+			})
+		})
+}
+
+fn with_config(mut info: InfoArgs, config: &Config) -> InfoArgs {
+	if info.names.is_empty() {
+		info.names = config.names.to_owned();
+
+		if info.emails.is_empty() {
+			info.emails = config.emails.to_owned();
 		}
-		_ => (),
 	}
 
-	#[allow(clippy::type_complexity)]
-	let (mut dir, mut emails, mut file, mut names, mut project, mut url, mut year): (
-		Option<String>,
-		Vec<String>,
-		Option<String>,
-		Vec<String>,
-		Option<String>,
-		Option<String>,
-		Option<String>,
-	) = (None, Vec::new(), None, Vec::new(), None, None, None);
+	if info.holders.is_empty() {
+		info.holders = config.holders.to_owned();
+	}
+
+	info.holder_format = info.holder_format.or(config.holder_format.to_owned());
+	info.project = info.project.or(config.project.to_owned());
+	info.url = info.url.or(config.url.to_owned());
+	info.year = info.year.or(config.year.to_owned());
 
+	if let Some(year) = info
+		.year
+		.as_ref()
+		.filter(|year| !year.chars().all(char::is_numeric))
 	{
-		let mut skip = false;
+		throw(LicerError::InvalidFlag {
+			flag: "year".to_owned(),
+			reason: format!(
+				"The provided year '{}' contains non-numeric characters!",
+				year
+			),
+		})
+	}
 
-		let get = |i: usize, flag: &str| match args.get(i + 1) {
-			Some(arg) => Some(arg.to_owned()),
-			None => CliError::InvalidFlag {
-				flag: flag.to_owned(),
-				reason: "Please provide a value!".to_owned(),
-			}
-			.throw(),
-		};
+	info
+}
+
+fn provided(info: &InfoArgs) -> Vec<Field> {
+	[
+		(Field::Year, info.year.is_some()),
+		(
+			Field::Names,
+			!info.names.is_empty() || !info.holders.is_empty(),
+		),
+		(Field::Emails, !info.emails.is_empty()),
+		(Field::Project, info.project.is_some()),
+		(Field::Url, info.url.is_some()),
+	]
+	.into_iter()
+	.filter_map(|(field, provided)| provided.then_some(field))
+	.collect()
+}
+
+fn resolve(root: &Project, fields: &[Field], values: Values) -> Values {
+	if !verbose() {
+		return values;
+	}
+
+	let values = resolve_values(root, fields, values).unwrap_or_else(|error| throw(error));
+
+	if !json() {
+		for (field, source) in &values.sources {
+			eprintln!(
+				"{} {} '{}' from {}",
+				"INFO".blue().bold(),
+				field.label(),
+				values.get(*field).unwrap_or_default(),
+				source
+			);
+		}
+	}
 
-		for (i, arg) in args.iter().enumerate() {
-			if skip || i < 2 {
-				skip = false;
-				continue;
+	values
+}
+
+fn sources(values: &Values) -> JsonValue {
+	let mut sources = JsonValue::new_object();
+
+	for (field, source) in &values.sources {
+		sources[field.key()] = source.to_owned().into();
+	}
+
+	sources
+}
+
+fn values(info: InfoArgs, lang: Option<String>) -> Values {
+	let holder_format = match info.holder_format {
+		None => HolderFormat::List,
+		Some(format) => HolderFormat::parse(&format).unwrap_or_else(|| {
+			throw(LicerError::InvalidFlag {
+				flag: "holder format".to_owned(),
+				reason: format!(
+					"The provided holder format '{}' is not one of list, lines, et-al or et-al=<number>!",
+					format
+				),
+			})
+		}),
+	};
+
+	Values {
+		holders: info.holders,
+		holder_format,
+		lang,
+		..Values::from_flags(info.emails, info.names, info.project, info.url, info.year)
+	}
+}
+
+fn license_file(root: &Project, file: Option<PathBuf>) -> PathBuf {
+	file.or_else(|| find_license_file(root)).unwrap_or_else(|| {
+		throw(LicerError::MissingFile {
+			kind: "license file".to_owned(),
+		})
+	})
+}
+
+fn check(root: &Project, file: Option<PathBuf>) -> JsonValue {
+	let file = license_file(root, file);
+	let license = check_license(root, Some(&file)).unwrap_or_else(|error| throw(error));
+
+	success(format!(
+		"The license file '{}' matches the manifest license '{}'",
+		file.to_string_lossy(),
+		license
+	));
+
+	object! {
+		file: file.to_string_lossy().into_owned(),
+		spdx: license,
+	}
+}
+
+fn detect(root: &Project, file: Option<PathBuf>) -> JsonValue {
+	let file = license_file(root, file);
+	let kind = file.to_string_lossy().into_owned();
+
+	let text = root.read(&file).unwrap_or_else(|_| {
+		throw(LicerError::FailedRead {
+			kind: kind.to_owned(),
+		})
+	});
+
+	match detect_license(&text).and_then(find_license) {
+		Some(license) => {
+			if !json() {
+				outln!("{} ({})", license.id, license.spdx);
 			}
 
-			match arg.as_str() {
-				"-d" | "--directory" => dir = get(i, "directory"),
-				"-e" | "--email" => emails.push(get(i, "email").unwrap()),
-				"-f" | "--file" => file = get(i, "file name"),
-				"-n" | "--name" => names.push(get(i, "name").unwrap()),
-				"-p" | "--project" => project = get(i, "project name"),
-				"-u" | "--url" => url = get(i, "project url"),
-				"-v" | "--version" => {
-					println!("Licer version {}", env!("CARGO_PKG_VERSION"));
-					return;
-				}
-				"-y" | "--year" => year = get(i, "year"),
-				flag if flag.starts_with("--") && flag.contains('=') => {
-					let split = flag.split('=').collect::<Vec<&str>>();
-					let arg = split[1].to_owned();
-
-					if arg.is_empty() {
-						CliError::InvalidFlag {
-							flag: flag[2..flag.len() - 1].to_owned(),
-							reason: "Please provide a value!".to_owned(),
-						}
-						.throw()
-					}
-
-					match split[0] {
-						"--directory" => dir = Some(arg),
-						"--email" => emails.push(arg),
-						"--file" => file = Some(arg),
-						"--name" => names.push(arg),
-						"--project" => project = Some(arg),
-						"--url" => url = Some(arg),
-						"--year" => year = Some(arg),
-						_ => CliError::UnknownFlag {
-							flag: flag.to_owned(),
-						}
-						.throw(),
-					}
-				}
-				flag if flag.starts_with('-') || flag.starts_with("--") => CliError::UnknownFlag {
-					flag: flag.to_owned(),
-				}
-				.throw(),
-				arg => CliError::UnknownArg {
-					arg: arg.to_owned(),
-				}
-				.throw(),
+			object! {
+				file: kind,
+				license: license.id,
+				spdx: license.spdx,
 			}
+		}
+		None => throw(LicerError::UnknownText { kind }),
+	}
+}
+
+fn header(root: &Project, config: Config, args: HeaderArgs) -> JsonValue {
+	let license = args
+		.license
+		.unwrap_or_else(|| default_license(root, &config));
+	let flags = provided(&args.info);
+	let mut values = values(with_config(args.info, &config), None);
+
+	values.add_source(&flags, "flag");
+	values.add_source(&Field::ALL, "config file");
+
+	let values = resolve(root, &[Field::Year, Field::Names], values);
+
+	let style = args.style.or(config.header_style).map(|style| {
+		CommentStyle::parse(&style).unwrap_or_else(|| {
+			throw(LicerError::InvalidFlag {
+				flag: "style".to_owned(),
+				reason: format!(
+					"The provided style '{}' is not one of slash, hash, block, dash or html!",
+					style
+				),
+			})
+		})
+	});
+
+	if args.files.is_empty() {
+		let header = render_header(
+			root,
+			&license,
+			values.clone(),
+			style.unwrap_or(CommentStyle::Slash),
+		)
+		.unwrap_or_else(|error| throw(error));
+
+		if !json() {
+			out!("{}", header);
+		}
+
+		return object! {
+			header: header,
+			sources: verbose().then(|| sources(&values)),
+		};
+	}
+
+	let mut files = Vec::new();
+
+	for file in args.files {
+		let style = style.unwrap_or_else(|| CommentStyle::for_path(&file));
+		let header = render_header(root, &license, values.clone(), style)
+			.unwrap_or_else(|error| throw(error));
 
-			skip = true;
+		let inserted = insert_header(root, &file, &header).unwrap_or_else(|error| throw(error));
+
+		match inserted {
+			true => success(format!(
+				"Added the license header to '{}'",
+				file.to_string_lossy()
+			)),
+			false if !json() && !quiet() => outln!(
+				"{} Skipped '{}' as it already has a license header",
+				"INFO".blue().bold(),
+				file.to_string_lossy()
+			),
+			false => (),
 		}
+
+		files.push(object! {
+			path: file.to_string_lossy().into_owned(),
+			inserted: inserted,
+		});
 	}
 
-	let dir = dir.map(PathBuf::from);
-	let file = file.map(PathBuf::from);
+	object! {
+		files: files,
+		sources: verbose().then(|| sources(&values)),
+	}
+}
+
+fn new(root: &Project, config: Config, args: NewArgs, init: bool) -> JsonValue {
+	let WriteArgs {
+		info,
+		lang,
+		directory,
+		file,
+		backup,
+		dry_run,
+		force,
+		gnu,
+		recursive,
+		stdout,
+	} = args.write;
+
+	let template = args
+		.template
+		.map(|template| match load_template(root, Path::new(&template)) {
+			Some(license) => license.id.to_owned(),
+			None => throw(LicerError::InvalidFlag {
+				flag: "template".to_owned(),
+				reason: format!("The template '{}' could not be loaded!", template),
+			}),
+		});
 
-	if dir.is_some() && !dir.as_ref().unwrap().is_dir() {
-		CliError::InvalidFlag {
+	let dir = directory.or(config.directory.to_owned()).map(PathBuf::from);
+	let file = file.or(config.file.to_owned()).map(PathBuf::from);
+
+	if let Some(dir) = dir.as_ref().filter(|dir| !root.fs().is_dir(dir)) {
+		throw(LicerError::InvalidFlag {
 			flag: "directory".to_owned(),
 			reason: format!(
 				"The provided directory '{}' is not a directory! Please provide the file name with the -f or --file flag!",
-				dir.unwrap().to_str().unwrap()
+				dir.to_str().unwrap()
 			),
-		}
-		.throw()
+		})
 	}
 
-	if file.is_some() && !file.as_ref().unwrap().is_file() {
-		CliError::InvalidFlag {
+	if let Some(file) = file.as_ref().filter(|file| file.components().count() != 1) {
+		throw(LicerError::InvalidFlag {
 			flag: "file name".to_owned(),
 			reason: format!(
 				"The provided file name '{}' contains a directory! Please provide the directory with the -d or --directory flag!",
-				file.unwrap().to_str().unwrap()
+				file.to_str().unwrap()
 			)
+		})
+	}
+
+	let flags = provided(&info);
+	let mut info = with_config(info, &config);
+
+	if init && json() {
+		throw(LicerError::InvalidFlag {
+			flag: "format".to_owned(),
+			reason: "The wizard can not be used with the json format! Please provide the license!"
+				.to_owned(),
+		})
+	}
+
+	let wizard = init && template.is_none() && args.license.is_none();
+	let license = match template.or(args.license) {
+		Some(license) => license,
+		None if wizard => {
+			let license;
+
+			(license, info.names, info.year, info.project) =
+				wizard::run(root, info.names, info.year, info.project);
+
+			license
 		}
-		.throw()
+		None => default_license(root, &config),
+	};
+
+	let gnu = gnu.then(|| {
+		find_license(&license)
+			.and_then(|license| license.gnu().map(|gnu| (license, gnu)))
+			.unwrap_or_else(|| {
+				throw(LicerError::InvalidFlag {
+					flag: "gnu".to_owned(),
+					reason: format!(
+						"The license '{}' is not one of agpl, gpl_two, gpl_three, lgpl_two or lgpl_three!",
+						license
+					),
+				})
+			})
+	});
+
+	let mut values = values(info, lang.or(config.lang));
+
+	values.add_source(&flags, "flag");
+
+	if wizard {
+		values.add_source(&Field::ALL, "wizard");
 	}
 
-	if year.is_some() && !year.as_ref().unwrap().chars().all(char::is_numeric) {
-		CliError::InvalidFlag {
-			flag: "year".to_owned(),
-			reason: format!(
-				"The provided year '{}' contains non-numeric characters!",
-				year.unwrap()
-			),
+	values.add_source(&Field::ALL, "config file");
+
+	let values = resolve(
+		root,
+		find_license(&license)
+			.map(|license| license.fields)
+			.unwrap_or_default(),
+		values,
+	);
+	let sources = verbose().then(|| sources(&values));
+
+	let (text, license_type) =
+		render_license(root, &license, values.clone()).unwrap_or_else(|error| throw(error));
+
+	let file = file
+		.or_else(|| gnu.as_ref().map(|(_, gnu)| PathBuf::from(gnu.file)))
+		.unwrap_or_else(|| PathBuf::from("LICENSE"));
+	let dir = dir.unwrap_or_else(|| PathBuf::from("./"));
+	let path = dir.join(&file);
+	let text = (text + "\n").trim_start().to_owned();
+
+	let companion = gnu
+		.as_ref()
+		.and_then(|(_, gnu)| gnu.companion)
+		.map(|companion| {
+			let (text, _) = render_license(root, companion.id, values.clone())
+				.unwrap_or_else(|error| throw(error));
+
+			(companion.id, (text + "\n").trim_start().to_owned())
+		});
+	let notice = gnu.and_then(|(license, _)| {
+		render_notice(root, license, values).unwrap_or_else(|error| throw(error))
+	});
+
+	let manifests = |updates: &[ManifestUpdate]| {
+		updates
+			.iter()
+			.map(|update| {
+				object! {
+					path: update.path.to_string_lossy().into_owned(),
+					license: update.license.to_owned(),
+					diff: update.diff.to_owned(),
+				}
+			})
+			.collect::<Vec<_>>()
+	};
+
+	if stdout || dry_run {
+		let mut updates = Vec::new();
+
+		if !json() {
+			out!("{}", text);
+		}
+
+		if dry_run {
+			updates = write_pkg(root, license_type, true).unwrap_or_else(|error| throw(error));
+
+			if recursive {
+				updates.extend(
+					write_members(root, &text, &file, license_type, true, |_| true, backup)
+						.unwrap_or_else(|error| throw(error))
+						.1,
+				);
+			}
+
+			if !json() {
+				if let Some((_, text)) = &companion {
+					out!("{}", text);
+				}
+
+				if let Some(notice) = &notice {
+					outln!("{}", notice);
+				}
+
+				for update in &updates {
+					out!("{}", update.diff);
+				}
+			}
+		}
+
+		return object! {
+			license: license,
+			spdx: license_type,
+			dry_run: dry_run,
+			text: text,
+			companion: companion.map(|(_, text)| text),
+			notice: notice,
+			files: JsonValue::new_array(),
+			backups: JsonValue::new_array(),
+			manifests: manifests(&updates),
+			sources: sources,
+		};
+	}
+
+	let (mut files, mut backups) = (Vec::new(), Vec::new());
+
+	let mut write_license = |license: &str, path: PathBuf, text: &str| {
+		if !confirm_overwrite(root, &path, text, force) {
+			throw(LicerError::ExistingLicense { path })
+		}
+
+		if backup {
+			if let Some(backup) = backup_file(root, &path).unwrap_or_else(|error| throw(error)) {
+				success(format!(
+					"Backed up '{}' to '{}'",
+					path.to_str().unwrap(),
+					backup.to_str().unwrap()
+				));
+				backups.push(backup.to_string_lossy().into_owned());
+			}
+		}
+
+		if root.fs().write(&path, text).is_err() {
+			throw(LicerError::FailedWrite { path })
 		}
-		.throw()
+
+		success(format!(
+			"Wrote license '{}' at '{}'",
+			license,
+			path.to_str().unwrap()
+		));
+		files.push(path.to_string_lossy().into_owned());
+	};
+
+	write_license(&license, path, &text);
+
+	if let Some((companion, text)) = &companion {
+		write_license(companion, dir.join("COPYING"), text);
 	}
 
-	let (license, license_type) = get_license(&args[1], emails, names, project, url, year);
+	if let Some(notice) = notice.as_ref().filter(|_| !json() && !quiet()) {
+		outln!(
+			"{} Add this notice to the README and the top of every source file:\n{}",
+			"INFO".blue().bold(),
+			notice
+		);
+	}
 
-	let path = dir
-		.unwrap_or_else(|| PathBuf::from("./"))
-		.join(file.unwrap_or_else(|| PathBuf::from("LICENSE")));
+	let mut updates = write_pkg(root, license_type, false).unwrap_or_else(|error| throw(error));
 
-	match write(&path, (license + "\n").trim_start()) {
-		Ok(_) => {
-			println!(
-				"{} Wrote license '{}' at '{}'",
-				"SUCCESS".green().bold(),
-				args[1],
+	if recursive {
+		let (written, members) = write_members(
+			root,
+			&text,
+			&file,
+			license_type,
+			false,
+			|path| confirm_overwrite(root, path, &text, force),
+			backup,
+		)
+		.unwrap_or_else(|error| throw(error));
+
+		for path in written {
+			success(format!(
+				"Wrote license '{}' at '{}'",
+				license,
 				path.to_str().unwrap()
-			);
+			));
+			files.push(path.to_string_lossy().into_owned());
 		}
-		Err(_) => CliError::FailedWrite { path }.throw(),
+
+		updates.extend(members);
 	}
 
-	write_pkg(license_type);
+	for update in &updates {
+		success(format!(
+			"Wrote license type '{}' to '{}'",
+			update.license,
+			update.path.to_str().unwrap()
+		));
+	}
+
+	object! {
+		license: license,
+		spdx: license_type,
+		dry_run: false,
+		text: JsonValue::Null,
+		companion: JsonValue::Null,
+		notice: notice,
+		files: files,
+		backups: backups,
+		manifests: manifests(&updates),
+		sources: sources,
+	}
 }
@@ -0,0 +1,229 @@
+use crate::config::user_config_path;
+use std::{
+	cell::RefCell,
+	collections::BTreeMap,
+	env::current_dir,
+	fs,
+	io::{Error, ErrorKind, Result},
+	path::{Path, PathBuf},
+	process::Command,
+};
+
+pub trait Fs {
+	fn read(&self, path: &Path) -> Result<String>;
+	fn write(&self, path: &Path, contents: &str) -> Result<()>;
+	fn is_file(&self, path: &Path) -> bool;
+	fn is_dir(&self, path: &Path) -> bool;
+	fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>>;
+
+	fn absolute(&self, path: &Path) -> PathBuf {
+		path.to_path_buf()
+	}
+}
+
+pub trait Vcs {
+	fn config(&self, dir: &Path) -> Option<String>;
+}
+
+pub struct Disk;
+
+fn disk(path: &Path) -> &Path {
+	if path.as_os_str().is_empty() {
+		Path::new(".")
+	} else {
+		path
+	}
+}
+
+impl Fs for Disk {
+	fn read(&self, path: &Path) -> Result<String> {
+		fs::read_to_string(disk(path))
+	}
+
+	fn write(&self, path: &Path, contents: &str) -> Result<()> {
+		fs::write(disk(path), contents)
+	}
+
+	fn is_file(&self, path: &Path) -> bool {
+		disk(path).is_file()
+	}
+
+	fn is_dir(&self, path: &Path) -> bool {
+		disk(path).is_dir()
+	}
+
+	fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>> {
+		fs::read_dir(disk(path))?
+			.map(|entry| entry.map(|entry| path.join(entry.file_name())))
+			.collect()
+	}
+
+	fn absolute(&self, path: &Path) -> PathBuf {
+		current_dir()
+			.map(|dir| dir.join(path))
+			.unwrap_or_else(|_| path.to_path_buf())
+	}
+}
+
+pub struct Git;
+
+impl Vcs for Git {
+	fn config(&self, dir: &Path) -> Option<String> {
+		let output = Command::new("git")
+			.arg("-C")
+			.arg(disk(dir))
+			.args(["config", "--get-regexp", "name|email"])
+			.output()
+			.ok()?;
+
+		String::from_utf8(output.stdout).ok()
+	}
+}
+
+pub struct NoVcs;
+
+impl Vcs for NoVcs {
+	fn config(&self, _: &Path) -> Option<String> {
+		None
+	}
+}
+
+#[derive(Default)]
+pub struct Memory {
+	files: RefCell<BTreeMap<PathBuf, String>>,
+}
+
+impl Memory {
+	pub fn new(files: &[(&str, &str)]) -> Memory {
+		Memory {
+			files: RefCell::new(
+				files
+					.iter()
+					.map(|(path, contents)| (PathBuf::from(path), contents.to_string()))
+					.collect(),
+			),
+		}
+	}
+}
+
+impl Fs for Memory {
+	fn read(&self, path: &Path) -> Result<String> {
+		self.files
+			.borrow()
+			.get(path)
+			.cloned()
+			.ok_or_else(|| Error::from(ErrorKind::NotFound))
+	}
+
+	fn write(&self, path: &Path, contents: &str) -> Result<()> {
+		self.files
+			.borrow_mut()
+			.insert(path.to_path_buf(), contents.to_owned());
+
+		Ok(())
+	}
+
+	fn is_file(&self, path: &Path) -> bool {
+		self.files.borrow().contains_key(path)
+	}
+
+	fn is_dir(&self, path: &Path) -> bool {
+		self.files
+			.borrow()
+			.keys()
+			.any(|file| file != path && file.starts_with(path))
+	}
+
+	fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>> {
+		if !self.is_dir(path) {
+			return Err(Error::from(ErrorKind::NotFound));
+		}
+
+		let mut entries = self
+			.files
+			.borrow()
+			.keys()
+			.filter_map(|file| file.strip_prefix(path).ok()?.components().next())
+			.map(|entry| path.join(entry))
+			.collect::<Vec<PathBuf>>();
+
+		entries.dedup();
+
+		Ok(entries)
+	}
+}
+
+pub struct Project {
+	dir: PathBuf,
+	fs: Box<dyn Fs>,
+	vcs: Box<dyn Vcs>,
+	user_config: Option<PathBuf>,
+}
+
+impl Project {
+	pub fn new(dir: impl Into<PathBuf>) -> Project {
+		Project {
+			dir: dir.into(),
+			fs: Box::new(Disk),
+			vcs: Box::new(Git),
+			user_config: user_config_path(),
+		}
+	}
+
+	pub fn current() -> Project {
+		Project::new("")
+	}
+
+	pub fn memory(files: &[(&str, &str)]) -> Project {
+		Project {
+			dir: PathBuf::new(),
+			fs: Box::new(Memory::new(files)),
+			vcs: Box::new(NoVcs),
+			user_config: None,
+		}
+	}
+
+	pub fn with_dir(mut self, dir: impl Into<PathBuf>) -> Project {
+		self.dir = dir.into();
+		self
+	}
+
+	pub fn with_fs(mut self, fs: impl Fs + 'static) -> Project {
+		self.fs = Box::new(fs);
+		self
+	}
+
+	pub fn with_vcs(mut self, vcs: impl Vcs + 'static) -> Project {
+		self.vcs = Box::new(vcs);
+		self
+	}
+
+	pub fn with_user_config(mut self, path: impl Into<PathBuf>) -> Project {
+		self.user_config = Some(path.into());
+		self
+	}
+
+	pub fn dir(&self) -> &Path {
+		&self.dir
+	}
+
+	pub fn path(&self, path: impl AsRef<Path>) -> PathBuf {
+		self.dir.join(path)
+	}
+
+	pub fn fs(&self) -> &dyn Fs {
+		self.fs.as_ref()
+	}
+
+	pub fn read(&self, path: impl AsRef<Path>) -> Result<String> {
+		self.fs.read(&self.path(path))
+	}
+
+	pub fn user_config(&self) -> Option<&Path> {
+		self.user_config.as_deref()
+	}
+
+	pub fn git_config(&self) -> Option<String> {
+		self.vcs.config(&self.dir)
+	}
+}
@@ -0,0 +1,181 @@
+use crate::{
+	error::collect_warnings,
+	licenses::{all_licenses, find_license, normalize_spdx},
+	render_text, Author, HolderFormat, LicerError, Project, Values,
+};
+
+pub struct LicenseRequest {
+	root: Project,
+	license: String,
+	values: Values,
+	appendix: bool,
+}
+
+pub struct GeneratedLicense {
+	pub text: String,
+	pub spdx: String,
+	pub files: Vec<String>,
+	pub warnings: Vec<LicerError>,
+}
+
+impl LicenseRequest {
+	pub fn new(license: impl Into<String>) -> LicenseRequest {
+		LicenseRequest {
+			root: Project::current(),
+			license: license.into(),
+			values: Values::from_flags(Vec::new(), Vec::new(), None, None, None),
+			appendix: true,
+		}
+	}
+
+	pub fn in_project(mut self, root: Project) -> LicenseRequest {
+		self.root = root;
+		self
+	}
+
+	pub fn author(mut self, name: impl Into<String>, email: Option<&str>) -> LicenseRequest {
+		self.values.authors.push(Author {
+			name: name.into(),
+			email: email.map(|email| email.to_owned()),
+		});
+		self
+	}
+
+	pub fn holder(mut self, holder: impl Into<String>) -> LicenseRequest {
+		self.values.holders.push(holder.into());
+		self
+	}
+
+	pub fn holder_format(mut self, format: HolderFormat) -> LicenseRequest {
+		self.values.holder_format = format;
+		self
+	}
+
+	pub fn year(mut self, year: u32) -> LicenseRequest {
+		self.values.year = Some(year.to_string());
+		self
+	}
+
+	pub fn years(mut self, start: u32, end: u32) -> LicenseRequest {
+		self.values.year = Some(if start == end {
+			start.to_string()
+		} else {
+			format!("{}-{}", start, end)
+		});
+		self
+	}
+
+	pub fn project(mut self, project: impl Into<String>) -> LicenseRequest {
+		self.values.project = Some(project.into());
+		self
+	}
+
+	pub fn url(mut self, url: impl Into<String>) -> LicenseRequest {
+		self.values.url = Some(url.into());
+		self
+	}
+
+	pub fn lang(mut self, lang: impl Into<String>) -> LicenseRequest {
+		self.values.lang = Some(lang.into());
+		self
+	}
+
+	pub fn appendix(mut self, appendix: bool) -> LicenseRequest {
+		self.appendix = appendix;
+		self
+	}
+
+	pub fn generate(self) -> Result<GeneratedLicense, LicerError> {
+		let (generated, warnings) = collect_warnings(|| self.render());
+		let (text, spdx, files) = generated?;
+
+		Ok(GeneratedLicense {
+			text,
+			spdx,
+			files,
+			warnings,
+		})
+	}
+
+	fn render(&self) -> Result<(String, String, Vec<String>), LicerError> {
+		let expression = self.license.replace('(', " ( ").replace(')', " ) ");
+		let tokens = expression.split_whitespace().collect::<Vec<_>>();
+
+		let (mut texts, mut ids, mut files) = (Vec::<String>::new(), Vec::new(), Vec::new());
+
+		for (i, token) in tokens.iter().enumerate() {
+			match *token {
+				"(" | ")" | "AND" | "OR" | "WITH" => ids.push(token.to_string()),
+				exception if i > 0 && tokens[i - 1] == "WITH" => {
+					let exception = spdx::exception_id(exception).ok_or_else(|| {
+						LicerError::UnknownLicense {
+							license: exception.to_owned(),
+						}
+					})?;
+
+					if let Some(text) = texts.last_mut() {
+						text.push_str("\n\n");
+						text.push_str(exception.text().trim());
+					}
+
+					ids.push(exception.name.to_owned());
+				}
+				id => {
+					let license = find_license(&canonical_id(id)).ok_or_else(|| {
+						LicerError::UnknownLicense {
+							license: id.to_owned(),
+						}
+					})?;
+
+					texts.push(
+						render_text(&self.root, license, self.values.clone(), self.appendix)?
+							.trim()
+							.to_owned(),
+					);
+					ids.push(license.spdx.to_owned());
+
+					if texts.len() == 1 {
+						files.extend(license.gnu().map(|gnu| gnu.file.to_owned()));
+					}
+				}
+			}
+		}
+
+		if texts.is_empty() {
+			return Err(LicerError::UnknownLicense {
+				license: self.license.to_owned(),
+			});
+		}
+
+		if texts.len() > 1 {
+			files.clear();
+		}
+
+		files.extend(["LICENSE".to_owned(), "LICENSE.txt".to_owned()]);
+
+		Ok((
+			texts.join(&format!("\n\n{}\n\n", "-".repeat(80))) + "\n",
+			ids.join(" ").replace("( ", "(").replace(" )", ")"),
+			files,
+		))
+	}
+}
+
+pub(crate) fn canonical_id(id: &str) -> String {
+	if all_licenses().iter().any(|license| license.id == id) {
+		return id.to_owned();
+	}
+
+	match normalize_spdx(id) {
+		Some(normalized) if !normalized.eq_ignore_ascii_case(id) => {
+			LicerError::DeprecatedLicense {
+				license: id.to_owned(),
+				id: normalized.to_owned(),
+			}
+			.warn();
+
+			normalized.to_owned()
+		}
+		_ => id.to_owned(),
+	}
+}
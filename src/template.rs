@@ -0,0 +1,120 @@
+use crate::{
+	licenses::{find_license, infer_fields, is_valid_spdx, leak, register_license, License, Rule},
+	LicerError, Project,
+};
+use std::path::Path;
+use toml_edit::Document;
+
+pub fn parse_template(id: &str, string: &str) -> Option<License> {
+	let (front, text) = match string.strip_prefix("+++") {
+		Some(rest) => {
+			let (front, text) = rest.split_once("\n+++")?;
+
+			(front.parse::<Document>().ok()?, text)
+		}
+		None => (Document::new(), string),
+	};
+
+	let get = |key: &str| front.get(key).and_then(|item| item.as_str());
+	let id = get("id").unwrap_or(id).to_owned();
+
+	let rules = |key: &str| -> Option<&'static [Rule]> {
+		let rules = match front.get(key) {
+			Some(item) => item
+				.as_array()?
+				.iter()
+				.map(|rule| Rule::parse(rule.as_str()?))
+				.collect::<Option<Vec<Rule>>>()?,
+			None => Vec::new(),
+		};
+
+		Some(Box::leak(rules.into_boxed_slice()))
+	};
+
+	Some(License {
+		name: leak(get("name").unwrap_or(&id).to_owned()),
+		spdx: leak(
+			get("spdx")
+				.map(|spdx| spdx.to_owned())
+				.unwrap_or_else(|| format!("LicenseRef-{}", id)),
+		),
+		fields: Box::leak(infer_fields(text).into_boxed_slice()),
+		permissions: rules("permissions")?,
+		conditions: rules("conditions")?,
+		limitations: rules("limitations")?,
+		languages: &[],
+		text: leak(format!("\n{}", text.trim())),
+		id: leak(id),
+	})
+}
+
+pub fn load_template(root: &Project, path: &Path) -> Option<&'static License> {
+	let kind = path.to_string_lossy().into_owned();
+
+	let string = match root.read(path) {
+		Ok(string) => string,
+		Err(_) => {
+			LicerError::FailedRead { kind }.warn();
+			return None;
+		}
+	};
+
+	let id = path.file_stem()?.to_string_lossy();
+
+	let license = match parse_template(&id, &string) {
+		Some(license) => license,
+		None => {
+			LicerError::FailedParse { kind }.warn();
+			return None;
+		}
+	};
+
+	if !is_valid_spdx(license.spdx) {
+		LicerError::InvalidSpdx {
+			id: license.spdx.to_owned(),
+			kind: kind.to_owned(),
+		}
+		.warn();
+	}
+
+	if let Some(existing) = find_license(license.id) {
+		if existing.text == license.text {
+			return Some(existing);
+		}
+
+		LicerError::DuplicateLicense {
+			id: license.id.to_owned(),
+			kind,
+		}
+		.warn();
+
+		return None;
+	}
+
+	Some(register_license(license))
+}
+
+pub fn load_templates(root: &Project, dir: &Path) -> Vec<&'static License> {
+	let mut paths = match root.fs().read_dir(&root.path(dir)) {
+		Ok(entries) => entries
+			.into_iter()
+			.filter(|path| root.fs().is_file(path))
+			.filter_map(|path| Some(dir.join(path.file_name()?)))
+			.collect::<Vec<_>>(),
+		Err(_) => {
+			LicerError::FailedRead {
+				kind: dir.to_string_lossy().into_owned(),
+			}
+			.warn();
+
+			return Vec::new();
+		}
+	};
+
+	paths.sort();
+
+	paths
+		.iter()
+		.filter_map(|path| load_template(root, path))
+		.collect()
+}
@@ -0,0 +1,147 @@
+use crate::{flush_warnings, throw};
+use colored::Colorize;
+use licer::{
+	current_year, find_info, find_license, narrow_licenses, Kind, LicerError, Project, LICENSES,
+};
+use std::{
+	io::{stderr, stdin, Write},
+	process::exit,
+};
+
+fn ask(question: &str, default: &str) -> String {
+	flush_warnings();
+
+	if default.is_empty() {
+		eprint!("{} {} ", "?".cyan().bold(), question);
+	} else {
+		eprint!("{} {} [{}] ", "?".cyan().bold(), question, default);
+	}
+
+	stderr().flush().ok();
+
+	let mut answer = String::new();
+
+	if stdin().read_line(&mut answer).unwrap_or(0) == 0 {
+		exit(1);
+	}
+
+	match answer.trim() {
+		"" => default.to_owned(),
+		answer => answer.to_owned(),
+	}
+}
+
+fn ask_bool(question: &str) -> Option<bool> {
+	match ask(&format!("{} (y/n/any)", question), "any")
+		.to_lowercase()
+		.as_str()
+	{
+		"y" | "yes" => Some(true),
+		"n" | "no" => Some(false),
+		_ => None,
+	}
+}
+
+pub fn run(
+	root: &Project,
+	names: Vec<String>,
+	year: Option<String>,
+	project: Option<String>,
+) -> (String, Vec<String>, Option<String>, Option<String>) {
+	eprintln!(
+		r#"
+Should changes to your project have to be shared under the same license?
+  1) No, anyone may use it in closed source work (permissive)
+  2) Only changes to your own files or library (weak copyleft)
+  3) Yes, anything built from it must use the same license (copyleft)
+  4) Nobody may use or share it without permission (proprietary)
+  5) No preference"#
+	);
+
+	let kind = match ask("Choice", "5").as_str() {
+		"1" => Some(Kind::Permissive),
+		"2" => Some(Kind::WeakCopyleft),
+		"3" => Some(Kind::Copyleft),
+		"4" => Some(Kind::Proprietary),
+		_ => None,
+	};
+
+	let mut licenses = if kind == Some(Kind::Proprietary) {
+		narrow_licenses(kind, None, None, None)
+	} else {
+		let patent_grant = ask_bool("Should contributors grant users an explicit patent license?");
+
+		let network_use = if kind.is_none() || kind == Some(Kind::Copyleft) {
+			ask_bool("Should users interacting with it over a network be offered the source?")
+		} else {
+			Some(false)
+		};
+
+		let attribution = ask_bool("Must copies keep your copyright notice for attribution?");
+
+		narrow_licenses(kind, patent_grant, network_use, attribution)
+	};
+
+	if licenses.is_empty() {
+		eprintln!("\nNo license matches every answer, so all licenses are listed instead");
+
+		licenses = LICENSES.to_vec();
+	}
+
+	eprintln!();
+
+	for (i, license) in licenses.iter().enumerate() {
+		eprintln!("{:>4}) {:<15}{}", i + 1, license.id, license.name);
+	}
+
+	let license = ask("License", "1");
+	let license = match license.parse::<usize>() {
+		Ok(i) if (1..=licenses.len()).contains(&i) => licenses[i - 1],
+		_ => find_license(&license).unwrap_or_else(|| {
+			throw(LicerError::UnknownLicense {
+				license: license.to_owned(),
+			})
+		}),
+	};
+
+	let pkg = find_info(root);
+
+	let names = ask(
+		"Name(s), separated by commas",
+		&if names.is_empty() {
+			pkg.authors
+				.iter()
+				.map(|author| author.name.to_owned())
+				.collect::<Vec<String>>()
+		} else {
+			names
+		}
+		.join(", "),
+	);
+
+	let year = ask(
+		"Year",
+		&year.unwrap_or_else(|| current_year().unwrap_or_else(|error| throw(error))),
+	);
+	let project = ask("Project name", &project.or(pkg.name).unwrap_or_default());
+
+	if !matches!(
+		ask(&format!("Write the {}?", license.name), "Y")
+			.to_lowercase()
+			.as_str(),
+		"y" | "yes"
+	) {
+		exit(1);
+	}
+
+	(
+		license.id.to_owned(),
+		names
+			.split(',')
+			.map(|name| name.trim().to_owned())
+			.filter(|name| !name.is_empty())
+			.collect(),
+		Some(year),
+		Some(project).filter(|project| !project.is_empty()),
+	)
+}
@@ -0,0 +1,260 @@
+use crate::project::{Fs, Project};
+use json::{parse, JsonValue};
+use std::path::PathBuf;
+use toml_edit::{Document, Item};
+
+pub fn is_inherited(item: &Item) -> bool {
+	item.get("workspace")
+		.and_then(|workspace| workspace.as_bool())
+		== Some(true)
+}
+
+pub fn find_cargo_root(root: &Project) -> Option<Document> {
+	let dir = root.fs().absolute(root.dir());
+
+	let (ancestor, toml) = dir
+		.ancestors()
+		.skip(1)
+		.filter_map(|ancestor| {
+			let string = root.fs().read(&ancestor.join("Cargo.toml")).ok()?;
+
+			Some((ancestor, string.parse::<Document>().ok()?))
+		})
+		.find(|(_, toml)| toml.contains_key("workspace"))?;
+
+	let path = dir
+		.strip_prefix(ancestor)
+		.ok()?
+		.components()
+		.map(|component| component.as_os_str().to_string_lossy().into_owned())
+		.collect::<Vec<String>>();
+
+	let patterns = |key: &str| {
+		toml["workspace"]
+			.get(key)
+			.and_then(|patterns| patterns.as_array())
+			.map(|patterns| {
+				patterns
+					.iter()
+					.filter_map(|pattern| pattern.as_str())
+					.map(|pattern| {
+						pattern
+							.split('/')
+							.filter(|segment| !segment.is_empty() && *segment != ".")
+							.collect::<Vec<&str>>()
+					})
+					.collect::<Vec<Vec<&str>>>()
+			})
+			.unwrap_or_default()
+	};
+
+	let member = patterns("members")
+		.iter()
+		.any(|pattern| matches_path(pattern, &path));
+	let excluded = patterns("exclude").iter().any(|pattern| {
+		pattern.len() <= path.len()
+			&& pattern
+				.iter()
+				.zip(&path)
+				.all(|(segment, name)| segment == name)
+	});
+
+	(member && !excluded).then_some(toml)
+}
+
+pub fn cargo_members(root: &Project, toml: &Document) -> Vec<PathBuf> {
+	let patterns = |key: &str| {
+		toml["workspace"]
+			.get(key)
+			.and_then(|patterns| patterns.as_array())
+			.map(|patterns| {
+				patterns
+					.iter()
+					.filter_map(|pattern| pattern.as_str())
+					.flat_map(|pattern| expand(root, pattern))
+					.collect::<Vec<PathBuf>>()
+			})
+			.unwrap_or_default()
+	};
+
+	let exclude = patterns("exclude");
+
+	patterns("members")
+		.into_iter()
+		.filter(|member| {
+			!member.as_os_str().is_empty()
+				&& !exclude.contains(member)
+				&& root.fs().is_file(&root.path(member.join("Cargo.toml")))
+		})
+		.collect()
+}
+
+pub fn js_members(root: &Project) -> Vec<PathBuf> {
+	let mut patterns = root
+		.read("package.json")
+		.ok()
+		.and_then(|string| parse(&string).ok())
+		.map(|json| {
+			let workspaces = match &json["workspaces"] {
+				JsonValue::Object(workspaces) => workspaces["packages"].clone(),
+				workspaces => workspaces.clone(),
+			};
+
+			workspaces
+				.members()
+				.filter_map(|pattern| pattern.as_str().map(|pattern| pattern.to_owned()))
+				.collect::<Vec<String>>()
+		})
+		.unwrap_or_default();
+
+	if let Ok(string) = root.read("pnpm-workspace.yaml") {
+		patterns.append(&mut read_pnpm(&string));
+	}
+
+	let (exclude, include): (Vec<String>, Vec<String>) = patterns
+		.into_iter()
+		.partition(|pattern| pattern.starts_with('!'));
+
+	let exclude = exclude
+		.iter()
+		.flat_map(|pattern| expand(root, &pattern[1..]))
+		.collect::<Vec<PathBuf>>();
+
+	include
+		.iter()
+		.flat_map(|pattern| expand(root, pattern))
+		.filter(|member| {
+			!member.as_os_str().is_empty()
+				&& !exclude.contains(member)
+				&& root.fs().is_file(&root.path(member.join("package.json")))
+		})
+		.collect()
+}
+
+fn read_pnpm(string: &str) -> Vec<String> {
+	let unquote = |item: &str| item.trim().trim_matches(['\'', '"']).to_owned();
+	let mut lines = string
+		.lines()
+		.map(|line| line.split(" #").next().unwrap())
+		.skip_while(|line| !line.starts_with("packages:"));
+
+	match lines.next().map(|line| line["packages:".len()..].trim()) {
+		Some(inline) if inline.starts_with('[') => inline
+			.trim_matches(['[', ']'])
+			.split(',')
+			.map(unquote)
+			.filter(|item| !item.is_empty())
+			.collect(),
+		Some(_) => lines
+			.take_while(|line| line.is_empty() || line.starts_with([' ', '\t', '-']))
+			.filter_map(|line| line.trim().strip_prefix('-'))
+			.map(unquote)
+			.collect(),
+		None => Vec::new(),
+	}
+}
+
+pub fn find_members(root: &Project) -> Vec<PathBuf> {
+	let mut members = js_members(root);
+
+	if let Some(toml) = root
+		.read("Cargo.toml")
+		.ok()
+		.and_then(|string| string.parse::<Document>().ok())
+		.filter(|toml| toml.contains_key("workspace"))
+	{
+		members.append(&mut cargo_members(root, &toml));
+	}
+
+	members.sort();
+	members.dedup();
+	members
+}
+
+pub fn expand(root: &Project, pattern: &str) -> Vec<PathBuf> {
+	let fs = root.fs();
+	let mut paths = vec![root.dir().to_path_buf()];
+
+	for segment in pattern.split('/').filter(|s| !s.is_empty() && *s != ".") {
+		paths = if segment == "**" {
+			let mut all = Vec::new();
+
+			for path in paths {
+				descendants(fs, path, &mut all);
+			}
+
+			all
+		} else if segment.contains(['*', '?']) {
+			paths
+				.iter()
+				.filter_map(|path| fs.read_dir(path).ok())
+				.flatten()
+				.filter(|entry| {
+					fs.is_dir(entry)
+						&& entry
+							.file_name()
+							.is_some_and(|name| matches(segment, &name.to_string_lossy()))
+				})
+				.collect()
+		} else {
+			paths
+				.into_iter()
+				.map(|path| path.join(segment))
+				.filter(|path| fs.is_dir(path))
+				.collect()
+		};
+	}
+
+	let mut paths = paths
+		.into_iter()
+		.map(|path| match path.strip_prefix(root.dir()) {
+			Ok(path) => path.to_path_buf(),
+			Err(_) => path,
+		})
+		.collect::<Vec<PathBuf>>();
+
+	paths.sort();
+	paths.dedup();
+	paths
+}
+
+fn descendants(fs: &dyn Fs, path: PathBuf, all: &mut Vec<PathBuf>) {
+	if let Ok(entries) = fs.read_dir(&path) {
+		for entry in entries {
+			let name = entry.file_name().unwrap_or_default();
+
+			if fs.is_dir(&entry) && name != "node_modules" && name != "target" {
+				descendants(fs, entry, all);
+			}
+		}
+	}
+
+	all.push(path);
+}
+
+fn matches_path(pattern: &[&str], path: &[String]) -> bool {
+	match (pattern.first(), path.first()) {
+		(None, None) => true,
+		(Some(&"**"), _) => {
+			matches_path(&pattern[1..], path)
+				|| (!path.is_empty() && matches_path(pattern, &path[1..]))
+		}
+		(Some(segment), Some(name)) => {
+			matches(segment, name) && matches_path(&pattern[1..], &path[1..])
+		}
+		_ => false,
+	}
+}
+
+fn matches(pattern: &str, name: &str) -> bool {
+	match (pattern.chars().next(), name.chars().next()) {
+		(None, None) => true,
+		(Some('*'), c) => {
+			matches(&pattern[1..], name)
+				|| c.is_some_and(|c| matches(pattern, &name[c.len_utf8()..]))
+		}
+		(Some('?'), Some(c)) => matches(&pattern[1..], &name[c.len_utf8()..]),
+		(Some(p), Some(c)) if p == c => matches(&pattern[p.len_utf8()..], &name[c.len_utf8()..]),
+		_ => false,
+	}
+}
@@ -1,27 +1,38 @@
-use licer::{get_license, read_cargo, read_git, read_node, write_pkg};
+use licer::{
+	all_licenses, backup_file, check_existing, check_license, collect_warnings, detect_license,
+	find_license, find_license_file, get_license, insert_header, is_valid_spdx, load_template,
+	load_templates, narrow_licenses, normalize_spdx, parse_template, read_cargo, read_config,
+	read_git, read_manifest_license, read_node, register_license, render_header, render_license,
+	render_notice, resolve_values, search_licenses, write_members, write_pkg, CommentStyle,
+	Existing, Field, HolderFormat, Kind, License, LicenseRequest, LicerError, Project, Rule,
+	Values, LICENSES,
+};
 use regex::Regex;
 use std::{
-	env::{set_current_dir, temp_dir},
-	fs::{read_to_string, remove_file, write},
+	env::temp_dir,
+	fs::{create_dir_all, read_to_string, remove_dir_all, write},
+	path::Path,
+	process::Command,
 };
 
 fn author_regex() -> Regex {
 	Regex::new(r"(?P<name>[^<>()\s]+)|(?:<(?P<email>.+?)>)|(?:\(.+?\))").unwrap()
 }
 
-fn temp_file(name: &'static str, contents: &'static str) -> impl FnOnce() {
-	set_current_dir(temp_dir()).unwrap();
-
-	write(name, contents.trim()).unwrap();
-
-	move || remove_file(name).unwrap()
+fn project(files: &[(&str, &str)]) -> Project {
+	Project::memory(
+		&files
+			.iter()
+			.map(|(path, contents)| (*path, contents.trim()))
+			.collect::<Vec<_>>(),
+	)
 }
 
 #[test]
 fn node_package_multiple_authors() {
 	let regex = author_regex();
 
-	let del_file = temp_file(
+	let root = project(&[(
 		"package.json",
 		r#"
 		{
@@ -34,11 +45,9 @@ fn node_package_multiple_authors() {
 				}
 			]
 		}"#,
-	);
-
-	let authors = read_node(&regex).unwrap().authors;
+	)]);
 
-	del_file();
+	let authors = read_node(&root, &regex).unwrap().authors;
 
 	assert_eq!(authors.len(), 3);
 	assert_eq!(authors[0].name, "John Doe");
@@ -53,7 +62,7 @@ fn node_package_multiple_authors() {
 fn node_package_singular_author() {
 	let regex = author_regex();
 
-	let del_file = temp_file(
+	let root = project(&[(
 		"package.json",
 		r#"
 		{
@@ -63,11 +72,9 @@ fn node_package_singular_author() {
 				"url": "https://johndoe.rs"
 			}
 		}"#,
-	);
-
-	let authors = read_node(&regex).unwrap().authors;
+	)]);
 
-	del_file();
+	let authors = read_node(&root, &regex).unwrap().authors;
 
 	assert_eq!(authors.len(), 1);
 	assert_eq!(authors[0].name, "John Doe");
@@ -78,16 +85,14 @@ fn node_package_singular_author() {
 fn cargo_package_multiple_authors() {
 	let regex = author_regex();
 
-	let del_file = temp_file(
+	let root = project(&[(
 		"Cargo.toml",
 		r#"
 		[package]
 		authors = ["John Appleseed (https://johnny.com) <johnappleseed@microsoft.us>", "Jane"]"#,
-	);
-
-	let authors = read_cargo(&regex).unwrap().authors;
+	)]);
 
-	del_file();
+	let authors = read_cargo(&root, &regex).unwrap().authors;
 
 	assert_eq!(authors.len(), 2);
 	assert_eq!(authors[0].name, "John Appleseed");
@@ -103,17 +108,15 @@ fn cargo_package_multiple_authors() {
 fn cargo_package_singular_author() {
 	let regex = author_regex();
 
-	let del_file = temp_file(
+	let root = project(&[(
 		"Cargo.toml",
 		r#"
 		[package]
 		authors = ["<johndoe@gmail.com> John Doe"]
 		"#,
-	);
-
-	let authors = read_cargo(&regex).unwrap().authors;
+	)]);
 
-	del_file();
+	let authors = read_cargo(&root, &regex).unwrap().authors;
 
 	assert_eq!(authors.len(), 1);
 	assert_eq!(authors[0].name, "John Doe");
@@ -122,8 +125,11 @@ fn cargo_package_singular_author() {
 
 #[test]
 fn mit_flag_provided() {
+	let root = project(&[]);
+
 	assert_eq!(
 		get_license(
+			&root,
 			"mit",
 			Vec::new(),
 			vec!["John".to_owned()],
@@ -131,6 +137,7 @@ fn mit_flag_provided() {
 			None,
 			Some("2025".to_owned())
 		)
+		.unwrap()
 		.0
 		.trim(),
 		r#"
@@ -161,6 +168,8 @@ SOFTWARE."#
 
 #[test]
 fn mit_git() {
+	let root = project(&[]);
+
 	let author = read_git(
 		r#"
 		user.email jane.user.email@yahoo.net
@@ -171,6 +180,7 @@ fn mit_git() {
 
 	assert_eq!(
 		get_license(
+			&root,
 			"mit",
 			Vec::new(),
 			vec![author.name],
@@ -178,6 +188,7 @@ fn mit_git() {
 			None,
 			Some("2025".to_owned())
 		)
+		.unwrap()
 		.0
 		.trim(),
 		r#"
@@ -208,7 +219,7 @@ SOFTWARE."#
 
 #[test]
 fn bsd_two_node_package() {
-	let del_file = temp_file(
+	let root = project(&[(
 		"package.json",
 		r#"
 		{
@@ -222,18 +233,18 @@ fn bsd_two_node_package() {
 				}
 			]
 		}"#,
-	);
+	)]);
 
 	let license = get_license(
+		&root,
 		"bsd_two",
 		Vec::new(),
 		Vec::new(),
 		None,
 		None,
 		Some("1983".to_owned()),
-	);
-
-	del_file();
+	)
+	.unwrap();
 
 	assert_eq!(
 		license.0.trim(),
@@ -268,25 +279,25 @@ OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE."#
 
 #[test]
 fn bsd_cargo_package() {
-	let del_file = temp_file(
+	let root = project(&[(
 		"Cargo.toml",
 		r#"
 [package]
 name = "licar"
 version = "0.1.0"
 authors = ["John Doe", "<janedoe@gmail.com> Jane Doe"]"#,
-	);
+	)]);
 
 	let license = get_license(
+		&root,
 		"bsd_two",
 		Vec::new(),
 		Vec::new(),
 		None,
 		None,
 		Some("2017".to_owned()),
-	);
-
-	del_file();
+	)
+	.unwrap();
 
 	assert_eq!(
 		license.0.trim(),
@@ -321,8 +332,11 @@ OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE."#
 
 #[test]
 fn ncsa_flag_provded() {
+	let root = project(&[]);
+
 	assert_eq!(
 		get_license(
+			&root,
 			"ncsa",
 			Vec::new(),
 			vec!["John".to_owned(), "Jane".to_owned()],
@@ -330,6 +344,7 @@ fn ncsa_flag_provded() {
 			Some("https://github.com".to_owned()),
 			Some("2025".to_owned())
 		)
+		.unwrap()
 		.0
 		.trim(),
 		r#"
@@ -356,7 +371,7 @@ subject to the following conditions:
     notice, this list of conditions and the following disclaimers in the
     documentation and/or other materials provided with the distribution.
 
-* Neither the names of [fullname], [project] nor the names of its
+* Neither the names of John, Jane, LICER nor the names of its
     contributors may be used to endorse or promote products derived from
     this Software without specific prior written permission.
 
@@ -373,7 +388,7 @@ THE SOFTWARE."#
 
 #[test]
 fn ncsa_node_package() {
-	let del_file = temp_file(
+	let root = project(&[(
 		"package.json",
 		r#"
 		{
@@ -391,18 +406,18 @@ fn ncsa_node_package() {
 			]
 		}
 		"#,
-	);
+	)]);
 
 	let license = get_license(
+		&root,
 		"ncsa",
 		Vec::new(),
 		Vec::new(),
 		None,
 		None,
 		Some("2000".to_owned()),
-	);
-
-	del_file();
+	)
+	.unwrap();
 
 	assert_eq!(
 		license.0.trim(),
@@ -430,7 +445,7 @@ subject to the following conditions:
     notice, this list of conditions and the following disclaimers in the
     documentation and/or other materials provided with the distribution.
 
-* Neither the names of [fullname], [project] nor the names of its
+* Neither the names of Linus Torvalds, John Cena, Jane Doe, gnu-js nor the names of its
     contributors may be used to endorse or promote products derived from
     this Software without specific prior written permission.
 
@@ -447,7 +462,7 @@ THE SOFTWARE."#
 
 #[test]
 fn ncsa_cargo_package() {
-	let del_file = temp_file(
+	let root = project(&[(
 		"Cargo.toml",
 		r#"
 		[package]
@@ -455,18 +470,18 @@ fn ncsa_cargo_package() {
         homepage = "https://rs.gnu.org"
         authors = ["(https://zahtec.com) Zahtec", "John"]
 		"#,
-	);
+	)]);
 
 	let license = get_license(
+		&root,
 		"ncsa",
 		Vec::new(),
 		Vec::new(),
 		None,
 		None,
 		Some("2000".to_owned()),
-	);
-
-	del_file();
+	)
+	.unwrap();
 
 	assert_eq!(
 		license.0.trim(),
@@ -494,7 +509,7 @@ subject to the following conditions:
     notice, this list of conditions and the following disclaimers in the
     documentation and/or other materials provided with the distribution.
 
-* Neither the names of [fullname], [project] nor the names of its
+* Neither the names of Zahtec, John, gnu-rs nor the names of its
     contributors may be used to endorse or promote products derived from
     this Software without specific prior written permission.
 
@@ -525,7 +540,7 @@ fn git_singular_author() {
 
 #[test]
 fn node_package_mit_write() {
-	let del_file = temp_file(
+	let root = project(&[(
 		"package.json",
 		r#"
         {
@@ -535,12 +550,12 @@ fn node_package_mit_write() {
             }
         }
         "#,
-	);
+	)]);
 
-	write_pkg("MIT");
+	write_pkg(&root, "MIT", false).unwrap();
 
 	assert_eq!(
-		read_to_string("package.json").unwrap().trim(),
+		root.read("package.json").unwrap().trim(),
 		r#"
 {
 	"name": "gnu-js",
@@ -551,29 +566,1362 @@ fn node_package_mit_write() {
 }"#
 		.trim()
 	);
-
-	del_file();
 }
 
 #[test]
 fn cargo_package_mit_write() {
-	let del_file = temp_file(
+	let root = project(&[(
 		"Cargo.toml",
 		r#"
 [package]
 authors = ["John Appleseed (https://johnny.com) <johnappleseed@microsoft.us>", "Jane"]"#,
-	);
+	)]);
 
-	write_pkg("MIT");
+	write_pkg(&root, "MIT", false).unwrap();
 
 	assert_eq!(
-		read_to_string("Cargo.toml").unwrap().trim(),
+		root.read("Cargo.toml").unwrap().trim(),
 		r#"
 [package]
 authors = ["John Appleseed (https://johnny.com) <johnappleseed@microsoft.us>", "Jane"]
 license = "MIT""#
 			.trim()
 	);
+}
+
+#[test]
+fn cargo_workspace_authors() {
+	let regex = author_regex();
+
+	let root = project(&[
+		(
+			"Cargo.toml",
+			r#"
+[workspace]
+members = ["crates/*"]
+exclude = ["crates/legacy"]
+
+[workspace.package]
+authors = ["John Doe <johndoe@gmail.com>"]
+homepage = "https://johndoe.rs""#,
+		),
+		(
+			"crates/core/Cargo.toml",
+			r#"
+[package]
+name = "core"
+authors.workspace = true"#,
+		),
+		(
+			"crates/legacy/Cargo.toml",
+			r#"
+[package]
+name = "legacy"
+authors.workspace = true"#,
+		),
+		(
+			"tools/gen/Cargo.toml",
+			r#"
+[package]
+name = "gen"
+authors.workspace = true"#,
+		),
+	]);
+
+	let workspace = read_cargo(&root, &regex).unwrap();
+
+	let root = root.with_dir("crates/core");
+
+	let member = read_cargo(&root, &regex).unwrap();
+
+	let root = root.with_dir("crates/legacy");
+
+	assert!(read_cargo(&root, &regex).unwrap().authors.is_empty());
+
+	let root = root.with_dir("tools/gen");
+
+	assert!(read_cargo(&root, &regex).unwrap().authors.is_empty());
+
+	assert_eq!(workspace.name, None);
+	assert_eq!(workspace.authors.len(), 1);
+	assert_eq!(workspace.authors[0].name, "John Doe");
+	assert_eq!(workspace.url, Some("https://johndoe.rs".to_owned()));
+	assert_eq!(member.name, Some("core".to_owned()));
+	assert_eq!(member.authors.len(), 1);
+	assert_eq!(
+		member.authors[0].email,
+		Some("johndoe@gmail.com".to_owned())
+	);
+}
+
+#[test]
+fn cargo_workspace_mit_write() {
+	let root = project(&[
+		(
+			"Cargo.toml",
+			r#"
+[workspace]
+members = ["crates/*"]
+exclude = ["crates/excluded"]"#,
+		),
+		(
+			"crates/core/Cargo.toml",
+			r#"
+[package]
+name = "core""#,
+		),
+		(
+			"crates/cli/Cargo.toml",
+			r#"
+[package]
+name = "cli"
+license = "Apache-2.0""#,
+		),
+		(
+			"crates/excluded/Cargo.toml",
+			r#"
+[package]
+name = "excluded""#,
+		),
+	]);
+
+	write_pkg(&root, "MIT", false).unwrap();
+
+	let (workspace, core, cli, excluded) = (
+		root.read("Cargo.toml").unwrap(),
+		root.read("crates/core/Cargo.toml").unwrap(),
+		root.read("crates/cli/Cargo.toml").unwrap(),
+		root.read("crates/excluded/Cargo.toml").unwrap(),
+	);
+
+	assert_eq!(
+		workspace.trim(),
+		r#"
+[workspace]
+members = ["crates/*"]
+exclude = ["crates/excluded"]
+
+[workspace.package]
+license = "MIT""#
+			.trim()
+	);
+	assert_eq!(
+		core.trim(),
+		r#"
+[package]
+name = "core"
+license.workspace = true"#
+			.trim()
+	);
+	assert_eq!(
+		cli.trim(),
+		r#"
+[package]
+name = "cli"
+license = "MIT""#
+			.trim()
+	);
+	assert_eq!(
+		excluded.trim(),
+		r#"
+[package]
+name = "excluded""#
+			.trim()
+	);
+}
+
+#[test]
+fn npm_workspace_recursive_write() {
+	let root = project(&[
+		(
+			"package.json",
+			r#"
+{
+	"name": "monorepo",
+	"workspaces": ["packages/*", "!packages/private"]
+}"#,
+		),
+		("packages/core/package.json", r#"{ "name": "core" }"#),
+		("packages/private/package.json", r#"{ "name": "private" }"#),
+		("packages/docs/README.md", "# Docs"),
+	]);
+
+	let (written, updates) = write_members(
+		&root,
+		"MIT License",
+		Path::new("LICENSE"),
+		"MIT",
+		false,
+		|_| false,
+		false,
+	)
+	.unwrap();
+
+	let (core, core_license, private, docs) = (
+		root.read("packages/core/package.json").unwrap(),
+		root.read("packages/core/LICENSE").unwrap(),
+		root.fs().is_file(Path::new("packages/private/LICENSE")),
+		root.fs().is_file(Path::new("packages/docs/LICENSE")),
+	);
+
+	assert_eq!(
+		core.trim(),
+		r#"
+{
+	"name": "core",
+	"license": "MIT"
+}"#
+		.trim()
+	);
+	assert_eq!(core_license, "MIT License");
+	assert!(!private);
+	assert!(!docs);
+	assert!(written.contains(&Path::new("packages/core/LICENSE").to_path_buf()));
+	assert!(updates
+		.iter()
+		.any(|update| update.path == Path::new("packages/core/package.json")));
+}
+
+#[test]
+fn pnpm_workspace_recursive_write() {
+	let root = project(&[
+		(
+			"pnpm-workspace.yaml",
+			r#"
+packages:
+  # all apps and packages
+  - 'apps/*'
+  - "packages/**"
+  - '!**/test/**'
+
+catalog:
+  react: ^18.0.0"#,
+		),
+		("apps/web/package.json", r#"{ "name": "web" }"#),
+		("packages/ui/button/package.json", r#"{ "name": "button" }"#),
+		("packages/ui/test/package.json", r#"{ "name": "test" }"#),
+	]);
+
+	write_members(
+		&root,
+		"ISC License",
+		Path::new("LICENSE.md"),
+		"ISC",
+		false,
+		|_| false,
+		false,
+	)
+	.unwrap();
+
+	let (web, button, test) = (
+		root.read("apps/web/LICENSE.md").unwrap(),
+		root.read("packages/ui/button/package.json").unwrap(),
+		root.fs().is_file(Path::new("packages/ui/test/LICENSE.md")),
+	);
+
+	assert_eq!(web, "ISC License");
+	assert!(button.contains(r#""license": "ISC""#));
+	assert!(!test);
+}
+
+#[test]
+fn cargo_package_dry_run() {
+	let root = project(&[(
+		"Cargo.toml",
+		r#"
+[package]
+name = "licer""#,
+	)]);
+
+	let updates = write_pkg(&root, "MIT", true).unwrap();
+	let toml = root.read("Cargo.toml").unwrap();
+
+	assert_eq!(
+		toml,
+		r#"
+[package]
+name = "licer""#
+			.trim()
+	);
+	assert_eq!(updates.len(), 1);
+	assert_eq!(updates[0].license, "MIT");
+	assert!(updates[0].diff.contains("+license = \"MIT\""));
+}
+
+#[test]
+fn detect_existing_licenses() {
+	let root = project(&[]);
+
+	let mit = get_license(
+		&root,
+		"mit",
+		Vec::new(),
+		vec!["John Doe".to_owned()],
+		None,
+		None,
+		Some("2003".to_owned()),
+	)
+	.unwrap()
+	.0;
+
+	assert_eq!(detect_license(&mit), Some("mit"));
+	assert_eq!(
+		detect_license(
+			&get_license(
+				&root,
+				"apache_two",
+				Vec::new(),
+				Vec::new(),
+				None,
+				None,
+				None
+			)
+			.unwrap()
+			.0
+			.replace('\n', " ")
+		),
+		Some("apache_two")
+	);
+	assert_eq!(
+		detect_license("All rights reserved. Do not distribute."),
+		None
+	);
+}
+
+#[test]
+fn existing_license_backup() {
+	let root = project(&[("LICENSE", "Hand-edited license")]);
+
+	let path = Path::new("LICENSE");
+	let (same, different, missing) = (
+		check_existing(&root, path, "Hand-edited license"),
+		check_existing(&root, path, "MIT License"),
+		check_existing(&root, Path::new("COPYING"), "MIT License"),
+	);
+	let (first, second) = (
+		backup_file(&root, path).unwrap(),
+		backup_file(&root, path).unwrap(),
+	);
+	let backup = root.read("LICENSE.bak").unwrap();
+
+	assert!(matches!(same, Existing::Identical));
+	assert!(matches!(different, Existing::Different { license: None }));
+	assert!(matches!(missing, Existing::Missing));
+	assert_eq!(first, Some(Path::new("LICENSE.bak").to_path_buf()));
+	assert_eq!(second, Some(Path::new("LICENSE.bak.1").to_path_buf()));
+	assert_eq!(backup, "Hand-edited license");
+}
+
+#[test]
+fn wizard_narrowing() {
+	let ids = |licenses: Vec<&licer::License>| {
+		licenses
+			.iter()
+			.map(|license| license.id)
+			.collect::<Vec<&str>>()
+	};
+
+	assert_eq!(
+		ids(narrow_licenses(
+			Some(Kind::Copyleft),
+			None,
+			Some(true),
+			None
+		)),
+		vec!["agpl", "eu_one", "eu_two", "osl"]
+	);
+	assert_eq!(
+		ids(narrow_licenses(
+			Some(Kind::WeakCopyleft),
+			Some(true),
+			Some(false),
+			None
+		)),
+		vec!["lgpl_three", "mozpl", "mrl"]
+	);
+	assert_eq!(
+		ids(narrow_licenses(
+			Some(Kind::Permissive),
+			Some(false),
+			Some(false),
+			Some(false)
+		)),
+		vec!["bsd_zero", "cc_zero", "mit_na", "unl"]
+	);
+}
+
+#[test]
+fn license_info() {
+	let ncsa = find_license("ncsa").unwrap();
+
+	assert_eq!(
+		ncsa.fields,
+		[Field::Year, Field::Names, Field::Project, Field::Url]
+	);
+	assert_eq!(
+		ncsa.fields.iter().map(Field::label).collect::<Vec<_>>(),
+		vec!["Year", "Name(s)", "Project Name", "Project URL"]
+	);
+
+	let apache = find_license("apache_two").unwrap();
+
+	assert!(apache.fields.is_empty());
+	assert!(apache.permissions.contains(&Rule::PatentUse));
+	assert!(apache.limitations.contains(&Rule::TrademarkUse));
+	assert_ne!(
+		Rule::PatentUse.description(false),
+		Rule::PatentUse.description(true)
+	);
+	assert!(find_license("nonexistent").is_none());
+}
+
+#[test]
+fn license_placeholders() {
+	for license in LICENSES {
+		let text = license.render(|field| field.key().to_owned()).unwrap();
+
+		assert!(!text.contains("{{"), "{}", license.id);
+	}
+
+	let license = License {
+		text: "Copyright (c) {{year}} {{holders}}",
+		fields: &[Field::Year],
+		..*find_license("mit").unwrap()
+	};
+
+	assert_eq!(
+		license.render(|_| "2025".to_owned()),
+		Err("{{holders}}".to_owned())
+	);
+}
+
+#[test]
+fn postgres_cargo_package() {
+	let root = project(&[(
+		"Cargo.toml",
+		r#"
+[package]
+name = "licar"
+version = "0.1.0"
+authors = ["John Doe"]"#,
+	)]);
+
+	let values = resolve_values(
+		&root,
+		find_license("postgres").unwrap().fields,
+		Values::from_flags(Vec::new(), Vec::new(), None, None, None),
+	)
+	.unwrap();
+
+	let license = get_license(
+		&root,
+		"postgres",
+		Vec::new(),
+		Vec::new(),
+		None,
+		None,
+		Some("2017".to_owned()),
+	)
+	.unwrap();
+
+	assert_eq!(values.get(Field::Names), Some("John Doe".to_owned()));
+	assert!(values.get(Field::Year).is_some());
+	assert_eq!(values.get(Field::Project), Some("licar".to_owned()));
+	assert!(license.0.contains("Copyright (c) 2017 John Doe"));
+	assert!(license.0.contains("EVEN IF John Doe\nHAS BEEN ADVISED"));
+}
+
+#[test]
+fn config_precedence() {
+	let root = project(&[
+		(
+			"xdg/licer/config.toml",
+			r#"
+license = "mit"
+name = ["Acme Corp.", "Jane Doe"]
+email = ["legal@acme.com"]
+year = 2020
+header_style = "hash"
+"#,
+		),
+		(
+			".licer.toml",
+			r#"
+license = "isc"
+file = "LICENSE.txt"
+header_style = "block"
+"#,
+		),
+	])
+	.with_user_config("xdg/licer/config.toml");
+
+	let config = read_config(&root);
+
+	assert_eq!(config.license, Some("isc".to_owned()));
+	assert_eq!(config.names, vec!["Acme Corp.", "Jane Doe"]);
+	assert_eq!(config.emails, vec!["legal@acme.com"]);
+	assert_eq!(config.year, Some("2020".to_owned()));
+	assert_eq!(config.file, Some("LICENSE.txt".to_owned()));
+	assert_eq!(config.header_style, Some("block".to_owned()));
+	assert_eq!(config.directory, None);
+}
+
+#[test]
+fn holder_formats() {
+	let root = project(&[]);
+
+	let values = |holders: &[&str], holder_format| Values {
+		holders: holders.iter().map(|holder| holder.to_string()).collect(),
+		holder_format,
+		..Values::from_flags(Vec::new(), Vec::new(), None, None, Some("2026".to_owned()))
+	};
+
+	assert_eq!(
+		render_license(
+			&root,
+			"mit",
+			values(&["Acme Corp. and contributors"], HolderFormat::List)
+		)
+		.unwrap()
+		.0
+		.lines()
+		.nth(3),
+		Some("Copyright (c) 2026 Acme Corp. and contributors")
+	);
+	assert_eq!(
+		render_license(
+			&root,
+			"bsd_two",
+			values(&["2019-2024 Acme Corp.", "Jane Doe"], HolderFormat::Lines)
+		)
+		.unwrap()
+		.0
+		.lines()
+		.skip(3)
+		.take(2)
+		.collect::<Vec<&str>>(),
+		vec![
+			"Copyright (c) 2019-2024, Acme Corp.",
+			"Copyright (c) 2026, Jane Doe"
+		]
+	);
+	assert_eq!(
+		values(&["A", "B", "C"], HolderFormat::EtAl(2)).get(Field::Names),
+		Some("A, B et al.".to_owned())
+	);
+	assert_eq!(
+		values(&["2019 A", "B"], HolderFormat::List).get(Field::Names),
+		Some("A, B".to_owned())
+	);
+	assert_eq!(
+		collect_warnings(|| render_license(
+			&root,
+			"mit",
+			values(&["2019 A", "B"], HolderFormat::List)
+		))
+		.1,
+		[LicerError::IgnoredHolderYears {
+			holder: "2019 A".to_owned()
+		}]
+	);
+	assert_eq!(
+		render_license(&root, "mit", values(&["2019 A"], HolderFormat::Lines))
+			.unwrap()
+			.0
+			.lines()
+			.nth(3),
+		Some("Copyright (c) 2019 A")
+	);
+	assert_eq!(HolderFormat::parse("et-al"), Some(HolderFormat::EtAl(1)));
+	assert_eq!(HolderFormat::parse("et-al=3"), Some(HolderFormat::EtAl(3)));
+	assert_eq!(HolderFormat::parse("et-al=0"), None);
+}
+
+#[test]
+fn custom_template() {
+	let root = project(&[]);
+
+	let template = parse_template(
+		"acme-mit",
+		r#"+++
+name = "Acme MIT"
++++
+Acme MIT
+
+Copyright (c) {{ year }} {{holders}}
+
+{{project}} may be used by anyone."#,
+	)
+	.unwrap();
+
+	assert_eq!(template.id, "acme-mit");
+	assert_eq!(template.name, "Acme MIT");
+	assert_eq!(template.spdx, "LicenseRef-acme-mit");
+	assert_eq!(template.fields, [Field::Year, Field::Names, Field::Project]);
+	assert_eq!(template.kind(), Kind::Unknown);
+
+	let template = parse_template(
+		"acme-mit",
+		r#"+++
+name = "Acme MIT"
+permissions = ["commercial-use", "modifications", "distribution"]
+conditions = ["include-copyright"]
+limitations = ["liability", "warranty"]
++++
+Acme MIT
+
+Copyright (c) {{ year }} {{holders}}
+
+{{project}} may be used by anyone."#,
+	)
+	.unwrap();
+
+	assert_eq!(template.kind(), Kind::Permissive);
+	assert_eq!(template.conditions, [Rule::IncludeCopyright]);
+	assert!(parse_template("acme", "+++\npermissions = [\"anything\"]\n+++\nAcme").is_none());
+
+	register_license(template);
+
+	let license = get_license(
+		&root,
+		"acme-mit",
+		Vec::new(),
+		vec!["Acme Corp.".to_owned()],
+		Some("Rocket".to_owned()),
+		None,
+		Some("2026".to_owned()),
+	)
+	.unwrap();
+
+	assert_eq!(
+		license.0.trim(),
+		"Acme MIT\n\nCopyright (c) 2026 Acme Corp.\n\nRocket may be used by anyone."
+	);
+	assert_eq!(license.1, "LicenseRef-acme-mit");
+	assert!(find_license("acme-mit").is_some());
+
+	let root = Project::memory(&[(
+		"repo/templates/acme-internal.txt",
+		"Acme Internal\n\nCopyright (c) {{year}} {{holders}}",
+	)])
+	.with_dir("repo");
+	let templates = load_templates(&root, Path::new("templates"));
+
+	assert_eq!(templates.len(), 1);
+	assert_eq!(templates[0].id, "acme-internal");
+	assert!(std::ptr::eq(
+		load_template(&root, Path::new("templates/acme-internal.txt")).unwrap(),
+		templates[0]
+	));
+}
+
+#[test]
+fn proprietary_write() {
+	let root = project(&[(
+		"package.json",
+		r#"
+        {
+            "name": "acme-internal"
+        }
+        "#,
+	)]);
+
+	let license = get_license(
+		&root,
+		"proprietary",
+		Vec::new(),
+		vec!["Acme Corp.".to_owned()],
+		None,
+		None,
+		Some("2026".to_owned()),
+	)
+	.unwrap();
+
+	write_pkg(&root, license.1, false).unwrap();
+
+	assert_eq!(
+		root.read("package.json").unwrap().trim(),
+		r#"
+{
+	"name": "acme-internal",
+	"private": true,
+	"license": "UNLICENSED"
+}"#
+		.trim()
+	);
+	assert_eq!(
+		read_manifest_license(&root).as_deref(),
+		Some("LicenseRef-Proprietary")
+	);
+
+	let root = project(&[(
+		"pyproject.toml",
+		r#"
+[project]
+name = "acme-internal""#,
+	)]);
+
+	write_pkg(&root, license.1, false).unwrap();
+
+	assert_eq!(
+		root.read("pyproject.toml").unwrap().trim(),
+		r#"
+[project]
+name = "acme-internal"
+license = "LicenseRef-Proprietary"
+classifiers = ["Private :: Do Not Upload"]"#
+			.trim()
+	);
+	assert_eq!(
+		read_manifest_license(&root).as_deref(),
+		Some("LicenseRef-Proprietary")
+	);
+
+	let root = project(&[("pyproject.toml", r#"project = "acme-internal""#)]);
+
+	assert!(matches!(
+		write_pkg(&root, license.1, false),
+		Err(LicerError::FailedParse { .. })
+	));
+
+	let root = project(&[(
+		"Cargo.toml",
+		r#"
+[package]
+name = "acme-internal""#,
+	)]);
+
+	write_pkg(&root, license.1, false).unwrap();
+
+	assert_eq!(
+		root.read("Cargo.toml").unwrap().trim(),
+		r#"
+[package]
+name = "acme-internal"
+license = "LicenseRef-Proprietary"
+publish = false"#
+			.trim()
+	);
+
+	assert!(license
+		.0
+		.contains("Copyright (c) 2026 Acme Corp.\nAll rights reserved."));
+	assert_eq!(
+		find_license("proprietary").unwrap().kind(),
+		Kind::Proprietary
+	);
+	assert!(!narrow_licenses(None, None, None, None)
+		.iter()
+		.any(|license| license.id == "proprietary"));
+}
+
+#[test]
+fn spdx_licenses() {
+	let root = project(&[]);
+
+	let license = get_license(
+		&root,
+		"BSD-1-Clause",
+		Vec::new(),
+		vec!["Acme Corp".to_owned()],
+		None,
+		None,
+		Some("2026".to_owned()),
+	)
+	.unwrap();
+
+	assert!(license
+		.0
+		.starts_with("\nCopyright (c) 2026 Acme Corp. All rights reserved."));
+	assert_eq!(license.1, "BSD-1-Clause");
+
+	let wtfpl = find_license("wtfpl").unwrap();
+
+	assert_eq!(wtfpl.spdx, "WTFPL");
+	assert!(wtfpl.fields.is_empty());
+	assert_eq!(wtfpl.kind(), Kind::Unknown);
+	assert!(std::ptr::eq(wtfpl, find_license("WTFPL").unwrap()));
+	assert!(!all_licenses().iter().any(|license| license.id == "WTFPL"));
+	assert_eq!(
+		find_license("BlueOak-1.0.0").unwrap().name,
+		"Blue Oak Model License 1.0.0"
+	);
+	assert_eq!(find_license("MIT").unwrap().id, "mit");
+	assert!(find_license("Foo-1.0").is_none());
+
+	let license = |id: &str| {
+		collect_warnings(|| {
+			get_license(
+				&root,
+				id,
+				Vec::new(),
+				vec!["Acme Corp".to_owned()],
+				Some("Rocket".to_owned()),
+				None,
+				Some("2026".to_owned()),
+			)
+			.unwrap()
+			.0
+		})
+	};
+	let unresolved = |id: &str, key: &str| {
+		vec![LicerError::UnresolvedPlaceholder {
+			license: id.to_owned(),
+			key: key.to_owned(),
+		}]
+	};
+
+	assert!(license("BSD-1-Clause").1.is_empty());
+	assert!(license("WTFPL").1.is_empty());
+	assert_eq!(
+		license("X11").1,
+		unresolved("X11", "Copyright (C) 1996 X Consortium")
+	);
+
+	let (busl, warnings) = license("BUSL-1.1");
+
+	assert!(busl.contains("Licensor:             Acme Corp\nLicensed Work:        Rocket\n"));
+	assert_eq!(warnings, unresolved("BUSL-1.1", "[Change Date]"));
+}
+
+#[test]
+fn valid_spdx_ids() {
+	for license in LICENSES {
+		assert!(is_valid_spdx(license.spdx), "{}", license.spdx);
+	}
+
+	assert_eq!(find_license("micpl").unwrap().spdx, "MS-PL");
+	assert_eq!(find_license("mrl").unwrap().spdx, "MS-RL");
+	assert_eq!(find_license("mulpl").unwrap().spdx, "MulanPSL-2.0");
+	assert_eq!(find_license("fdl").unwrap().spdx, "GFDL-1.3-only");
+	assert!(!is_valid_spdx("GPL-3.0"));
+	assert!(!is_valid_spdx("MICROSOFT-PL"));
+
+	assert_eq!(normalize_spdx("GPL-3.0"), Some("GPL-3.0-only"));
+	assert_eq!(normalize_spdx("GPL-2.0+"), Some("GPL-2.0-or-later"));
+	assert_eq!(normalize_spdx("LGPL-2.1"), Some("LGPL-2.1-only"));
+	assert_eq!(normalize_spdx("BSD-2-Clause-FreeBSD"), Some("BSD-2-Clause"));
+	assert_eq!(normalize_spdx("mit"), Some("MIT"));
+	assert_eq!(normalize_spdx("MIT OR Apache-2.0"), None);
+}
+
+#[test]
+fn deprecated_manifest_license() {
+	let root = project(&[(
+		"Cargo.toml",
+		r#"
+[package]
+name = "licar"
+license = "GPL-3.0""#,
+	)]);
+
+	let license = read_manifest_license(&root);
+
+	assert_eq!(license, Some("GPL-3.0-only".to_owned()));
+	assert_eq!(find_license("GPL-3.0-only").unwrap().id, "gpl_three");
+}
+
+#[test]
+fn license_translations() {
+	let root = project(&[]);
+
+	let cecill = find_license("cecill").unwrap();
+
+	assert_eq!(cecill.langs(), vec!["en", "fr"]);
+	assert!(cecill
+		.translation(None)
+		.unwrap()
+		.contains("CeCILL FREE SOFTWARE LICENSE AGREEMENT"));
+	assert!(cecill
+		.translation(Some("fr"))
+		.unwrap()
+		.contains("CONTRAT DE LICENCE DE LOGICIEL LIBRE CeCILL"));
+	assert!(find_license("mulpl")
+		.unwrap()
+		.translation(Some("zh"))
+		.unwrap()
+		.contains("木兰宽松许可证"));
+	assert_eq!(find_license("eu_two").unwrap().langs(), vec!["en"]);
+	assert!(find_license("eu_two")
+		.unwrap()
+		.translation(Some("de"))
+		.is_none());
+	assert_eq!(
+		detect_license(
+			&render_license(
+				&root,
+				"mulpl",
+				Values {
+					lang: Some("zh".to_owned()),
+					..Values::from_flags(
+						Vec::new(),
+						vec!["Acme".to_owned()],
+						Some("Rocket".to_owned()),
+						None,
+						Some("2026".to_owned())
+					)
+				}
+			)
+			.unwrap()
+			.0
+		),
+		Some("mulpl")
+	);
+}
+
+#[test]
+fn gnu_conventions() {
+	let root = project(&[]);
+
+	let lgpl = find_license("lgpl_three").unwrap().gnu().unwrap();
+
+	assert_eq!(lgpl.file, "COPYING.LESSER");
+	assert_eq!(lgpl.companion.unwrap().id, "gpl_three");
+	assert_eq!(
+		find_license("gpl_two").unwrap().gnu().unwrap().file,
+		"COPYING"
+	);
+	assert!(find_license("gpl_two")
+		.unwrap()
+		.gnu()
+		.unwrap()
+		.companion
+		.is_none());
+	assert!(find_license("mit").unwrap().gnu().is_none());
+
+	let notice = render_notice(
+		&root,
+		find_license("agpl").unwrap(),
+		Values::from_flags(
+			Vec::new(),
+			vec!["Acme".to_owned()],
+			Some("Rocket".to_owned()),
+			None,
+			Some("2026".to_owned()),
+		),
+	)
+	.unwrap()
+	.unwrap();
+
+	assert!(notice.starts_with("\nRocket\nCopyright (C) 2026 Acme\n"));
+	assert!(notice.contains("GNU Affero General Public License"));
+}
+
+#[test]
+fn library_errors() {
+	let root = project(&[]);
+
+	let error = render_license(
+		&root,
+		"unknown",
+		Values::from_flags(Vec::new(), Vec::new(), None, None, None),
+	)
+	.unwrap_err();
+
+	assert_eq!(
+		error,
+		LicerError::UnknownLicense {
+			license: "unknown".to_owned()
+		}
+	);
+	assert_eq!(error.to_string(), "Unknown license 'unknown'!");
+
+	let error: Box<dyn std::error::Error> = Box::new(
+		render_license(
+			&root,
+			"eu_two",
+			Values {
+				lang: Some("de".to_owned()),
+				..Values::from_flags(Vec::new(), Vec::new(), None, None, None)
+			},
+		)
+		.unwrap_err(),
+	);
+
+	assert!(error.to_string().contains("Available languages: en"));
+}
+
+#[test]
+fn license_request() {
+	let mit = LicenseRequest::new("mit")
+		.holder("Acme")
+		.years(2019, 2024)
+		.generate()
+		.unwrap();
+
+	assert!(mit
+		.text
+		.starts_with("MIT License\n\nCopyright (c) 2019-2024 Acme\n"));
+	assert_eq!(mit.spdx, "MIT");
+	assert_eq!(mit.files, vec!["LICENSE", "LICENSE.txt"]);
+	assert!(mit.warnings.is_empty());
+
+	let apache = LicenseRequest::new("apache_two")
+		.appendix(false)
+		.generate()
+		.unwrap();
+
+	assert!(apache.text.ends_with("END OF TERMS AND CONDITIONS\n"));
+
+	let mulpl = LicenseRequest::new("mulpl")
+		.in_project(project(&[]))
+		.appendix(false)
+		.generate()
+		.unwrap();
+
+	assert!(!mulpl.text.contains("{{") && mulpl.warnings.is_empty());
+
+	let dual = LicenseRequest::new("(MIT OR Apache-2.0)")
+		.holder("Acme")
+		.year(2026)
+		.generate()
+		.unwrap();
+
+	assert_eq!(dual.spdx, "(MIT OR Apache-2.0)");
+	assert!(dual.text.contains("Copyright (c) 2026 Acme"));
+	assert!(dual.text.contains("Apache License"));
+
+	let gpl = LicenseRequest::new("GPL-3.0").generate().unwrap();
+
+	assert_eq!(gpl.spdx, "GPL-3.0-only");
+	assert_eq!(gpl.files[0], "COPYING");
+	assert_eq!(
+		gpl.warnings,
+		vec![LicerError::DeprecatedLicense {
+			license: "GPL-3.0".to_owned(),
+			id: "GPL-3.0-only".to_owned()
+		}]
+	);
+
+	let classpath = LicenseRequest::new("GPL-2.0-only WITH Classpath-exception-2.0")
+		.generate()
+		.unwrap();
+
+	assert_eq!(classpath.spdx, "GPL-2.0-only WITH Classpath-exception-2.0");
+	assert!(classpath
+		.text
+		.contains("As a special exception, the copyright holders of this library"));
+	assert!(LicenseRequest::new("nope").generate().is_err());
+}
+
+#[test]
+fn explicit_project_root() {
+	let dir = temp_dir().join("licer_explicit_root");
 
-	del_file();
+	create_dir_all(dir.join("crates/core")).unwrap();
+	write(
+		dir.join("Cargo.toml"),
+		"[workspace]\nmembers = [\"crates/*\"]",
+	)
+	.unwrap();
+	write(
+		dir.join("crates/core/Cargo.toml"),
+		"[package]\nname = \"core\"\nauthors = [\"Jane Doe\"]",
+	)
+	.unwrap();
+
+	let root = Project::new(&dir);
+
+	write_pkg(&root, "MIT", false).unwrap();
+
+	let core = read_to_string(dir.join("crates/core/Cargo.toml")).unwrap();
+	let generated = LicenseRequest::new("mit")
+		.in_project(Project::new(dir.join("crates/core")))
+		.year(2026)
+		.generate()
+		.unwrap();
+
+	remove_dir_all(&dir).unwrap();
+
+	assert!(core.contains("license.workspace = true"));
+	assert!(generated.text.contains("Copyright (c) 2026 Jane Doe"));
+}
+
+#[test]
+fn license_headers() {
+	let root = project(&[
+		("main.rs", "fn main() {}"),
+		("run.sh", "#!/bin/sh\necho"),
+		("lib.rs", "// SPDX-License-Identifier: MIT"),
+	]);
+	let values = Values {
+		holders: vec!["Acme".to_owned()],
+		..Values::from_flags(Vec::new(), Vec::new(), None, None, Some("2026".to_owned()))
+	};
+
+	assert_eq!(
+		render_header(
+			&root,
+			"mit OR apache_two",
+			values.clone(),
+			CommentStyle::Slash
+		)
+		.unwrap(),
+		"// SPDX-FileCopyrightText: 2026 Acme\n// SPDX-License-Identifier: MIT OR Apache-2.0\n"
+	);
+	assert_eq!(
+		CommentStyle::for_path(Path::new("run.sh")),
+		CommentStyle::Hash
+	);
+
+	let header = render_header(&root, "mit", values, CommentStyle::Hash).unwrap();
+
+	assert!(insert_header(&root, Path::new("run.sh"), &header).unwrap());
+	assert!(!insert_header(&root, Path::new("lib.rs"), &header).unwrap());
+	assert_eq!(
+		root.read("run.sh").unwrap(),
+		"#!/bin/sh\n# SPDX-FileCopyrightText: 2026 Acme\n# SPDX-License-Identifier: MIT\n\necho"
+	);
+}
+
+#[test]
+fn check_license_file() {
+	let root = project(&[]);
+	let (text, _) = render_license(
+		&root,
+		"isc",
+		Values::from_flags(
+			Vec::new(),
+			vec!["Acme".to_owned()],
+			None,
+			None,
+			Some("2026".to_owned()),
+		),
+	)
+	.unwrap();
+
+	let root = Project::memory(&[
+		("COPYING", &text),
+		(
+			"Cargo.toml",
+			"[package]\nname = \"core\"\nlicense = \"MIT\"",
+		),
+	]);
+
+	assert_eq!(find_license_file(&root), Some("COPYING".into()));
+	assert_eq!(
+		check_license(&root, None),
+		Err(LicerError::LicenseMismatch {
+			found: "ISC".to_owned(),
+			id: "MIT".to_owned(),
+		})
+	);
+
+	root.fs()
+		.write(
+			Path::new("Cargo.toml"),
+			"[package]\nname = \"core\"\nlicense = \"ISC\"",
+		)
+		.unwrap();
+
+	assert_eq!(check_license(&root, None), Ok("ISC"));
+	assert_eq!(
+		check_license(&project(&[]), None),
+		Err(LicerError::MissingFile {
+			kind: "license file".to_owned(),
+		})
+	);
+}
+
+#[test]
+fn shell_completions() {
+	for shell in ["bash", "zsh", "fish", "powershell", "elvish"] {
+		let output = Command::new(env!("CARGO_BIN_EXE_licer"))
+			.args(["completions", shell])
+			.output()
+			.unwrap();
+		let script = String::from_utf8(output.stdout).unwrap();
+
+		assert!(output.status.success());
+		assert!(script.contains("header"));
+		assert!(
+			script.contains("lgpl_three") && script.contains("Apache-2.0"),
+			"{}",
+			shell
+		);
+
+		if matches!(shell, "powershell" | "elvish") {
+			assert_eq!(
+				script
+					.lines()
+					.filter(|line| line.contains("'Apache-2.0'"))
+					.count(),
+				4,
+				"{}",
+				shell
+			);
+		}
+	}
+}
+
+#[test]
+fn man_pages() {
+	let dir = temp_dir().join("licer_man_pages");
+
+	create_dir_all(&dir).unwrap();
+
+	let output = Command::new(env!("CARGO_BIN_EXE_licer"))
+		.args(["manpage", "-d"])
+		.arg(&dir)
+		.output()
+		.unwrap();
+	let licer = read_to_string(dir.join("licer.1")).unwrap();
+	let new = read_to_string(dir.join("licer-new.1")).unwrap();
+	let header = read_to_string(dir.join("licer-header.1")).unwrap();
+
+	remove_dir_all(&dir).unwrap();
+
+	assert!(output.status.success());
+	assert!(licer.contains(".SH LICENSES") && licer.contains("lgpl_three"));
+	assert!(new.contains(".SH LICENSES") && new.contains("\\-\\-holder\\-format"));
+	assert!(!header.contains(".SH LICENSES"));
+}
+
+#[test]
+fn json_output() {
+	let licer = |args: &[&str]| {
+		let output = Command::new(env!("CARGO_BIN_EXE_licer"))
+			.args(args)
+			.output()
+			.unwrap();
+
+		(
+			output.status.code(),
+			json::parse(&String::from_utf8(output.stdout).unwrap()).unwrap(),
+		)
+	};
+
+	let (code, info) = licer(&["info", "mit", "--format", "json"]);
+
+	assert_eq!(code, Some(0));
+	assert_eq!(info["spdx"], "MIT");
+	assert_eq!(info["fields"][0], "year");
+
+	let (_, list) = licer(&["--format=json", "list"]);
+
+	assert_eq!(list["licenses"].len(), LICENSES.len());
+
+	let (_, list) = licer(&[
+		"list",
+		"--copyleft",
+		"--osi",
+		"--requires=email",
+		"--format=json",
+	]);
+
+	assert_eq!(list["licenses"].len(), 1);
+	assert_eq!(list["licenses"][0]["id"], "ofl");
+
+	let (code, error) = licer(&["--format", "json", "new", "unknown_license"]);
+
+	assert_eq!(code, Some(3));
+	assert_eq!(error["error"]["kind"], "UnknownLicense");
+	assert_eq!(
+		error["error"]["message"],
+		"Unknown license 'unknown_license'!"
+	);
+
+	let (code, error) = licer(&["--format", "json", "compare", "mit"]);
+
+	assert_eq!(code, Some(2));
+	assert_eq!(error["error"]["kind"], "MissingArg");
+}
+
+#[test]
+fn field_sources() {
+	let root = project(&[(
+		"Cargo.toml",
+		r#"
+		[package]
+		name = "rocket"
+		authors = ["Jane Doe <jane@doe.dev>"]
+		"#,
+	)]);
+
+	let mut values =
+		Values::from_flags(Vec::new(), Vec::new(), None, None, Some("2026".to_owned()));
+
+	values.add_source(&Field::ALL, "flag");
+
+	let values =
+		resolve_values(&root, &[Field::Year, Field::Names, Field::Project], values).unwrap();
+
+	assert_eq!(
+		values.sources,
+		vec![
+			(Field::Year, "flag".to_owned()),
+			(Field::Names, "Cargo.toml".to_owned()),
+			(Field::Project, "Cargo.toml".to_owned()),
+		]
+	);
+
+	let licer = |args: &[&str]| {
+		let output = Command::new(env!("CARGO_BIN_EXE_licer"))
+			.args(args)
+			.output()
+			.unwrap();
+
+		(
+			output.status.code(),
+			String::from_utf8(output.stdout).unwrap(),
+			String::from_utf8(output.stderr).unwrap(),
+		)
+	};
+
+	let (code, output, errors) = licer(&[
+		"--verbose",
+		"header",
+		"-l",
+		"mit",
+		"-n",
+		"Acme",
+		"-y",
+		"2026",
+	]);
+
+	assert_eq!(code, Some(0));
+	assert!(errors.contains("Year '2026' from flag") && errors.contains("'Acme' from flag"));
+	assert!(!output.contains("INFO"));
+
+	let (code, output, _) = licer(&["-q", "search", "zzzz"]);
+
+	assert_eq!((code, output), (Some(0), String::new()));
+	assert_eq!(licer(&["new", "unknown_license"]).0, Some(3));
+	assert_eq!(licer(&["check", "-f", "missing"]).0, Some(5));
+	assert_eq!(licer(&["new", "mit", "--holder-format", "bad"]).0, Some(2));
+	assert_eq!(
+		LicerError::ExistingLicense {
+			path: Path::new("LICENSE").to_path_buf()
+		}
+		.exit_code(),
+		1
+	);
+}
+
+#[test]
+fn license_search() {
+	let ids = |licenses: Vec<&License>| {
+		licenses
+			.iter()
+			.map(|license| license.id)
+			.collect::<Vec<&str>>()
+	};
+
+	assert_eq!(ids(search_licenses("bsd-2-clause")), ["bsd_two"]);
+	assert_eq!(ids(search_licenses("Mulan")), ["mulpl"]);
+	assert!(ids(search_licenses("affero general public")).contains(&"agpl"));
+	assert!(ids(search_licenses("network server")).contains(&"agpl"));
+	assert!(search_licenses("no such license text").is_empty());
+	assert_eq!(ids(search_licenses("MIT"))[..2], ["mit", "mit_na"]);
+	assert_eq!(ids(search_licenses("GPL-2.0-only"))[0], "gpl_two");
+	assert!(search_licenses("permitted").is_empty());
+
+	let mit = find_license("mit").unwrap();
+	let proprietary = find_license("proprietary").unwrap();
+
+	assert!(mit.osi_approved() && mit.fsf_libre());
+	assert!(!proprietary.osi_approved() && !proprietary.fsf_libre());
+	assert_eq!(Field::parse("name"), Some(Field::Names));
+	assert_eq!(Field::parse("holders"), Some(Field::Names));
+	assert_eq!(Field::parse("author"), None);
 }